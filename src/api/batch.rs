@@ -0,0 +1,24 @@
+use crate::api::error::{Error, ItemError};
+use crate::api::v5::{BatchItem, Request};
+use crate::api::Rest;
+
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+impl Rest {
+    /// Sends a batch request and returns the result of every item, in request order.
+    ///
+    /// Items rejected by the exchange (`sCode` other than 0) are returned as [`ItemError`],
+    /// whether the batch partially failed (code 2), failed (code 1) or succeeded.
+    /// Any other failure is returned as the error of the whole request.
+    pub async fn request_batch<R, T>(&self, req: R) -> Result<Vec<Result<T, ItemError>>, Error>
+    where
+        R: Request<Response = Vec<T>>,
+        T: BatchItem + DeserializeOwned + Debug,
+    {
+        match self.request(req).await {
+            Ok(items) => Ok(items.into_iter().map(BatchItem::into_result).collect()),
+            Err(err) => err.into_item_results(),
+        }
+    }
+}
@@ -52,7 +52,6 @@ impl Credential {
         (self.api_key.as_str(), signed)
     }
 
-    #[warn(dead_code)]
     pub(crate) fn signature_websocket(
         &self,
         method: Method,
@@ -1,12 +1,19 @@
-use std::fmt::Debug;
+use crate::api::v5::BatchItem;
+use crate::api::TransportError;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 use thiserror::Error;
 
-pub type Result<T> = std::result::Result<T, Error<T>>;
+pub type Result<T> = std::result::Result<T, Error>;
 
+/// Error of any request, independent of the response type,
+/// so errors of different endpoints can be stored and sent together
 #[derive(Debug, Error)]
-pub enum Error<T: Debug> {
+pub enum Error {
     #[error("Api error: {0}")]
-    Api(ApiError<T>),
+    Api(Box<ApiError>),
 
     #[error("placing limit order requires price")]
     PlacingLimitOrderRequiresPrice,
@@ -14,21 +21,453 @@ pub enum Error<T: Debug> {
     #[error("endpoint requires auth but no secret configured")]
     NoSecretConfigured,
 
+    #[error("rate limit of {path} reached, retry in {retry_after:?}")]
+    RateLimited {
+        path: &'static str,
+        retry_after: Duration,
+    },
+
+    #[error("gave up after {retries} retries: {last:?}")]
+    Retry { retries: u32, last: Box<Error> },
+
     #[error(transparent)]
     SerdeQs(#[from] serde_qs::Error),
 
+    #[error("unexpected http status {status} from {path}")]
+    Status {
+        status: StatusCode,
+        path: String,
+        /// `x-request-id` header of the response
+        request_id: Option<String>,
+    },
+
     #[error(transparent)]
-    Reqwest(#[from] reqwest::Error),
+    Transport(#[from] TransportError),
+
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
+
+    #[error(transparent)]
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+
+    #[error("websocket connection closed")]
+    Disconnected,
+
+    #[error("no websocket response within {0:?}")]
+    Timeout(Duration),
 
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 }
 
-#[derive(Debug, Error)]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Error::WebSocket(Box::new(err))
+    }
+}
+
+impl Error {
+    /// Whether the failure is transient and the request can be sent again:
+    /// timeouts, connection failures, 5xx/429 responses and the OKX rate limit / system busy codes.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Transport(err) => err.is_transient(),
+            Error::Status { status, .. } => is_retryable_status(*status),
+            Error::Api(err) => {
+                err.error_code().is_some_and(OkxErrorCode::is_retryable)
+                    || err.status.is_some_and(is_retryable_status)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the request was rejected by the client or exchange rate limit
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Error::RateLimited { .. } => true,
+            Error::Status { status, .. } => *status == StatusCode::TOO_MANY_REQUESTS,
+            Error::Api(err) => {
+                err.error_code().is_some_and(OkxErrorCode::is_rate_limited)
+                    || err.status == Some(StatusCode::TOO_MANY_REQUESTS)
+            }
+            Error::Retry { last, .. } => last.is_rate_limited(),
+            _ => false,
+        }
+    }
+
+    /// HTTP status of the REST response
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Api(err) => err.status,
+            Error::Status { status, .. } => Some(*status),
+            Error::Retry { last, .. } => last.status(),
+            _ => None,
+        }
+    }
+
+    /// Path of the REST request, e.g. `/api/v5/trade/order`
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Error::Api(err) => err.path.as_deref(),
+            Error::Status { path, .. } => Some(path),
+            Error::RateLimited { path, .. } => Some(path),
+            Error::Retry { last, .. } => last.path(),
+            _ => None,
+        }
+    }
+
+    /// `x-request-id` header of the REST response, to quote when contacting OKX support
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::Api(err) => err.request_id.as_deref(),
+            Error::Status { request_id, .. } => request_id.as_deref(),
+            Error::Retry { last, .. } => last.request_id(),
+            _ => None,
+        }
+    }
+
+    /// Result of every item of a failed (code 1) or partially failed (code 2) batch,
+    /// `Err(self)` for any other error
+    pub fn into_item_results<T>(
+        self,
+    ) -> std::result::Result<Vec<std::result::Result<T, ItemError>>, Self>
+    where
+        T: BatchItem + DeserializeOwned,
+    {
+        match &self {
+            Error::Api(err) if matches!(err.code, Some(1 | 2)) => {
+                match err.parse_data::<Vec<T>>() {
+                    Some(Ok(items)) => Ok(items.into_iter().map(BatchItem::into_result).collect()),
+                    _ => Err(self),
+                }
+            }
+            _ => Err(self),
+        }
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+#[derive(Debug, Error, Default)]
 #[error("{self:?}")]
-pub struct ApiError<T: Debug> {
+pub struct ApiError {
     pub code: Option<u64>,
     pub msg: Option<String>,
-    pub data: Option<T>,
+    /// Raw `data` of the response, see [`ApiError::parse_data`]
+    pub data: Option<serde_json::Value>,
     pub conn_id: Option<String>,
+    /// HTTP status of the REST response
+    pub status: Option<StatusCode>,
+    /// Path of the REST request
+    pub path: Option<String>,
+    /// `x-request-id` header of the REST response
+    pub request_id: Option<String>,
+}
+
+impl ApiError {
+    pub fn error_code(&self) -> Option<OkxErrorCode> {
+        self.code.map(OkxErrorCode::from)
+    }
+
+    /// Parses `data` as the response type of the request,
+    /// e.g. `Vec<PlaceOrderResponse>` to read the `s_code` of every order
+    pub fn parse_data<T: DeserializeOwned>(&self) -> Option<serde_json::Result<T>> {
+        self.data.as_ref().map(T::deserialize)
+    }
+}
+
+/// Item of a batch rejected by the exchange, see [`BatchItem`]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("item rejected with code {code}: {msg}")]
+pub struct ItemError {
+    pub code: OkxErrorCode,
+    pub msg: String,
+}
+
+/// Category of [`OkxErrorCode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Missing, invalid or insufficient API key, signature or permissions
+    Auth,
+    /// Rate limit reached, the request can be sent again later
+    RateLimit,
+    /// Exchange busy or unavailable
+    Service,
+    /// Missing or invalid request parameters
+    InvalidParameter,
+    InsufficientBalance,
+    /// Order filled, canceled or unknown
+    OrderNotFound,
+    /// Order rejected by the instrument or risk rules
+    OrderRejected,
+    /// Instrument unknown, expired, or being delivered or settled
+    InstrumentUnavailable,
+    /// Account blocked, restricted or in the wrong mode
+    Account,
+    /// Transfer, deposit or withdrawal rejected
+    Funding,
+    Other,
+}
+
+macro_rules! okx_error_codes {
+    ($($(#[$doc:meta])* $variant:ident = $code:literal => $category:ident,)+) => {
+        /// Documented OKX error code, returned as `code` or as `sCode` of batch items.
+        /// https://www.okx.com/docs-v5/en/#error-code
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum OkxErrorCode {
+            $($(#[$doc])* $variant,)+
+            /// Code without a variant
+            Other(u64),
+        }
+
+        impl OkxErrorCode {
+            pub const fn code(self) -> u64 {
+                match self {
+                    $(Self::$variant => $code,)+
+                    Self::Other(code) => code,
+                }
+            }
+
+            pub const fn category(self) -> ErrorCategory {
+                match self {
+                    $(Self::$variant => ErrorCategory::$category,)+
+                    Self::Other(50100..=50199) => ErrorCategory::Auth,
+                    Self::Other(58000..=58999) => ErrorCategory::Funding,
+                    Self::Other(_) => ErrorCategory::Other,
+                }
+            }
+        }
+
+        impl From<u64> for OkxErrorCode {
+            fn from(code: u64) -> Self {
+                match code {
+                    $($code => Self::$variant,)+
+                    code => Self::Other(code),
+                }
+            }
+        }
+    };
+}
+
+okx_error_codes! {
+    /// Body can not be empty
+    BodyEmpty = 50000 => InvalidParameter,
+    /// Service temporarily unavailable
+    ServiceUnavailable = 50001 => Service,
+    /// JSON data format error
+    JsonFormatError = 50002 => InvalidParameter,
+    /// Endpoint request timeout, the request may still have been processed
+    RequestTimeout = 50004 => Service,
+    /// API is offline or unavailable
+    ApiOffline = 50005 => Service,
+    /// Invalid Content-Type, please use "application/JSON" format
+    InvalidContentType = 50006 => InvalidParameter,
+    /// Account blocked
+    AccountBlocked = 50007 => Account,
+    /// User does not exist
+    UserNotFound = 50008 => Auth,
+    /// Rate limit reached
+    RateLimitReached = 50011 => RateLimit,
+    /// Account status invalid
+    AccountStatusInvalid = 50012 => Account,
+    /// Systems are busy, please try again later
+    SystemBusy = 50013 => Service,
+    /// Parameter can not be empty
+    ParameterEmpty = 50014 => InvalidParameter,
+    /// Either one of two parameters is required
+    ParameterRequired = 50015 => InvalidParameter,
+    /// System error, try again later
+    SystemError = 50026 => Service,
+    /// The account is restricted from trading
+    AccountRestricted = 50027 => Account,
+    /// Sub-account rate limit reached
+    SubAccountRateLimitReached = 50061 => RateLimit,
+    /// API frozen, please contact customer service
+    ApiFrozen = 50100 => Auth,
+    /// APIKey does not match current environment
+    ApiKeyEnvironmentMismatch = 50101 => Auth,
+    /// Timestamp request expired
+    TimestampExpired = 50102 => Auth,
+    /// Request header OK-ACCESS-KEY can not be empty
+    AccessKeyEmpty = 50103 => Auth,
+    /// Request header OK-ACCESS-PASSPHRASE can not be empty
+    PassphraseEmpty = 50104 => Auth,
+    /// Request header OK-ACCESS-PASSPHRASE incorrect
+    PassphraseIncorrect = 50105 => Auth,
+    /// Request header OK-ACCESS-SIGN can not be empty
+    SignEmpty = 50106 => Auth,
+    /// Request header OK-ACCESS-TIMESTAMP can not be empty
+    TimestampEmpty = 50107 => Auth,
+    /// Your IP is not included in your API key's IP whitelist
+    IpNotWhitelisted = 50110 => Auth,
+    /// Invalid OK-ACCESS-KEY
+    InvalidAccessKey = 50111 => Auth,
+    /// Invalid OK-ACCESS-TIMESTAMP
+    InvalidTimestamp = 50112 => Auth,
+    /// Invalid signature
+    InvalidSignature = 50113 => Auth,
+    /// Invalid authorization
+    InvalidAuthorization = 50114 => Auth,
+    /// Your API key doesn't have permission to use this endpoint
+    PermissionDenied = 50120 => Auth,
+    /// Parameter error
+    ParameterError = 51000 => InvalidParameter,
+    /// Instrument ID does not exist
+    InstrumentNotFound = 51001 => InstrumentUnavailable,
+    /// Order quantity exceeds current tier limit
+    TierLimitExceeded = 51004 => OrderRejected,
+    /// Order price is not within the price limit
+    PriceOutOfLimit = 51006 => OrderRejected,
+    /// Order failed, insufficient balance
+    InsufficientBalance = 51008 => InsufficientBalance,
+    /// Order placement function is blocked by the platform
+    OrderPlacementBlocked = 51009 => Account,
+    /// Operation is not supported under the current account mode
+    AccountModeUnsupported = 51010 => Account,
+    /// Order ID already exists
+    DuplicateOrderId = 51011 => OrderRejected,
+    /// Duplicated client order ID
+    DuplicateClientOrderId = 51016 => OrderRejected,
+    /// Order amount should be greater than the minimum available amount
+    OrderBelowMinimum = 51020 => OrderRejected,
+    /// Contract expired
+    ContractExpired = 51027 => InstrumentUnavailable,
+    /// Contract under delivery
+    ContractUnderDelivery = 51028 => InstrumentUnavailable,
+    /// Contract is being settled
+    ContractSettling = 51029 => InstrumentUnavailable,
+    /// Funding fee is being settled
+    FundingFeeSettling = 51030 => InstrumentUnavailable,
+    /// Maximum number of orders placed in bulk exceeded
+    BatchSizeExceeded = 51111 => InvalidParameter,
+    /// Order failed, insufficient balance
+    OrderInsufficientBalance = 51119 => InsufficientBalance,
+    /// Order quantity must be a multiple of the lot size
+    LotSizeMismatch = 51121 => OrderRejected,
+    /// Order cancellation failed, the order has been filled, canceled or does not exist
+    CancelFailed = 51400 => OrderNotFound,
+    /// Order cancellation failed, the order is already canceled
+    OrderAlreadyCanceled = 51401 => OrderNotFound,
+    /// Order cancellation failed, the order is already completed
+    OrderAlreadyCompleted = 51402 => OrderNotFound,
+    /// Order amendment failed, the order has been filled, canceled or does not exist
+    AmendFailed = 51503 => OrderNotFound,
+    /// Order does not exist
+    OrderNotFound = 51603 => OrderNotFound,
+    /// Transfer suspended
+    TransferSuspended = 58101 => Funding,
+    /// Funding rate limit reached
+    FundingRateLimitReached = 58102 => RateLimit,
+    /// Sub-account does not exist
+    SubAccountNotFound = 58115 => Funding,
+    /// Withdrawal amount exceeds daily withdrawal limit
+    WithdrawalLimitExceeded = 58201 => Funding,
+    /// Withdrawal address isn't on the verified address list
+    WithdrawalAddressNotVerified = 58207 => Funding,
+    /// Insufficient balance
+    FundingInsufficientBalance = 58350 => InsufficientBalance,
+    /// Close any open positions or orders before modifying settings
+    SettingsFailed = 59000 => Account,
+    /// Leverage exceeds the maximum limit for this instrument
+    LeverageTooHigh = 59102 => InvalidParameter,
+    /// Account margin is insufficient and leverage is too low
+    InsufficientMargin = 59103 => InsufficientBalance,
+    /// Insufficient account balance
+    AccountInsufficientBalance = 59200 => InsufficientBalance,
+    /// Only the APIKey of the main account has permission
+    MasterApiKeyRequired = 59500 => Auth,
+    /// APIKey does not exist
+    ApiKeyNotFound = 59506 => Auth,
+}
+
+impl OkxErrorCode {
+    /// Whether the exchange was busy or rate limited, and the request can be sent again later
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::ServiceUnavailable | Self::SystemBusy | Self::SystemError
+        ) || self.is_rate_limited()
+    }
+
+    pub fn is_rate_limited(self) -> bool {
+        self.category() == ErrorCategory::RateLimit
+    }
+}
+
+impl Display for OkxErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: u64) -> Error {
+        Error::Api(Box::new(ApiError {
+            code: Some(code),
+            ..Default::default()
+        }))
+    }
+
+    fn status(status: StatusCode) -> Error {
+        Error::Status {
+            status,
+            path: "/api/v5/trade/order".to_owned(),
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn test_retryable_api_codes() {
+        assert!(api_error(50011).is_retryable());
+        assert!(api_error(50013).is_retryable());
+        assert!(api_error(50061).is_retryable());
+        assert!(!api_error(51008).is_retryable());
+        assert!(!Error::NoSecretConfigured.is_retryable());
+        assert!(status(StatusCode::BAD_GATEWAY).is_retryable());
+        assert!(!status(StatusCode::UNAUTHORIZED).is_retryable());
+        assert!(Error::Transport(TransportError::Timeout).is_retryable());
+    }
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(OkxErrorCode::from(51008), OkxErrorCode::InsufficientBalance);
+        assert_eq!(OkxErrorCode::from(51008).code(), 51008);
+        assert_eq!(
+            OkxErrorCode::from(51400).category(),
+            ErrorCategory::OrderNotFound
+        );
+        assert_eq!(OkxErrorCode::from(50199).category(), ErrorCategory::Auth);
+        assert_eq!(OkxErrorCode::from(12345).category(), ErrorCategory::Other);
+        assert!(OkxErrorCode::FundingRateLimitReached.is_rate_limited());
+        assert!(!OkxErrorCode::SystemBusy.is_rate_limited());
+
+        assert!(api_error(50061).is_rate_limited());
+        assert!(!api_error(50013).is_rate_limited());
+        assert!(status(StatusCode::TOO_MANY_REQUESTS).is_rate_limited());
+        assert!(api_error(50026).is_retryable());
+        let overloaded = Error::Api(Box::new(ApiError {
+            code: Some(50000),
+            status: Some(StatusCode::TOO_MANY_REQUESTS),
+            ..Default::default()
+        }));
+        assert!(overloaded.is_rate_limited() && overloaded.is_retryable());
+        assert!(!api_error(50004).is_retryable());
+    }
+
+    #[test]
+    fn test_error_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<Error>();
+
+        let err: anyhow::Error = status(StatusCode::BAD_GATEWAY).into();
+        assert_eq!(
+            err.to_string(),
+            "unexpected http status 502 Bad Gateway from /api/v5/trade/order"
+        );
+    }
 }
@@ -3,36 +3,51 @@ use crate::api::error::Error;
 use crate::api::v5::{ApiResponse, Request};
 use chrono::Utc;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::{Client, ClientBuilder, Method, Url};
+use reqwest::{Method, Url};
 use std::convert::TryInto;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::Arc;
 
 use self::error::ApiError;
 
+mod batch;
 mod option;
+mod paginate;
+mod rate_limit;
+mod registry;
+mod retry;
+mod transport;
+mod websocket;
 
 pub mod credential;
 pub mod error;
 pub use self::option::*;
+pub use self::rate_limit::*;
+pub use self::registry::*;
+pub use self::retry::*;
+pub use self::transport::*;
+pub use self::websocket::*;
 pub mod v5;
 
 #[derive(Clone)]
 pub struct Rest {
     options: Options,
-    client: Client,
+    transport: Arc<dyn Transport>,
+    rate_limiter: RateLimiter,
 }
 
 impl Rest {
     pub fn new(options: Options) -> Self {
-        let client = ClientBuilder::new()
-            .tcp_nodelay(true)
-            .tcp_keepalive(Duration::from_secs(30))
-            .timeout(Duration::from_secs(30))
-            .build()
-            .unwrap();
+        Self::with_transport(options, ReqwestTransport::default())
+    }
 
-        Self { client, options }
+    /// Sends requests through a custom [`Transport`] instead of the default reqwest client
+    pub fn with_transport(options: Options, transport: impl Transport + 'static) -> Self {
+        Self {
+            options,
+            transport: Arc::new(transport),
+            rate_limiter: RateLimiter::default(),
+        }
     }
 
     #[inline]
@@ -57,9 +72,57 @@ impl Rest {
     where
         R: Request,
     {
+        let policy = match &self.options.retry {
+            Some(policy) if req.is_idempotent() => policy,
+            _ => return self.send(&req, on_send).await,
+        };
+
+        let mut retries = 0;
+        loop {
+            match self.send(&req, on_send).await {
+                Ok(data) => return Ok(data),
+                Err(err) if retries < policy.max_retries && err.is_retryable() => {
+                    let delay = policy.backoff(retries);
+                    log::warn!("{} failed, retrying in {:?}: {}", R::PATH, delay, err);
+                    retries += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) if retries == 0 => return Err(err),
+                Err(err) => {
+                    return Err(Error::Retry {
+                        retries,
+                        last: Box::new(err),
+                    })
+                }
+            }
+        }
+    }
+
+    async fn send<R>(
+        &self,
+        req: &R,
+        on_send: &mut (dyn FnMut() + Sync + Send),
+    ) -> crate::api::error::Result<R::Response>
+    where
+        R: Request,
+    {
+        if let (Some(mode), Some(limit)) = (self.options.rate_limit, R::RATE_LIMIT) {
+            let (key, cost) = (req.rate_limit_key(), req.rate_limit_cost());
+            if let Err(retry_after) = self
+                .rate_limiter
+                .acquire(R::PATH, key, &limit, cost, mode)
+                .await
+            {
+                return Err(Error::RateLimited {
+                    path: R::PATH,
+                    retry_after,
+                });
+            }
+        }
+
         let (params, body) = match R::METHOD {
-            Method::GET => (Some(serde_qs::to_string(&req)?), String::new()),
-            _ => (None, serde_json::to_string(&req)?),
+            Method::GET => (Some(serde_qs::to_string(req)?), String::new()),
+            _ => (None, serde_json::to_string(req)?),
         };
         let mut path = req.path().into_owned();
         if let Some(params) = params {
@@ -69,8 +132,9 @@ impl Rest {
             }
         }
 
-        let url = format!("{}{}", self.options().rest(), path);
+        let url = Url::parse(&format!("{}{}", self.options().rest(), path))?;
         log::debug!("{} {}", url, body);
+        let endpoint = url.path().to_owned();
         let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
 
         let mut headers = HeaderMap::new();
@@ -90,8 +154,7 @@ impl Rest {
                 Err(_) => return Err(Error::NoSecretConfigured),
             };
 
-            let (key, signature) =
-                credential.signature(R::METHOD, &timestamp, &Url::from_str(&url).unwrap(), &body);
+            let (key, signature) = credential.signature(R::METHOD, &timestamp, &url, &body);
 
             headers.insert(
                 HeaderName::from_str("OK-ACCESS-KEY").unwrap(),
@@ -120,53 +183,317 @@ impl Rest {
             }
         }
 
-        let sent = match self
-            .client
-            .request(R::METHOD, &url)
-            .headers(headers)
-            .body(body)
-            .send()
-            .await
-        {
+        let request = HttpRequest {
+            method: R::METHOD,
+            url,
+            headers,
+            body,
+        };
+        let sent = match self.transport.send(request).await {
             Ok(sent) => sent,
             Err(err) => {
                 log::error!("{err}");
-                return Err(Error::Reqwest(err));
+                return Err(Error::Transport(err));
             }
         };
 
-        if let Err(err) = sent.error_for_status_ref() {
-            return Err(Error::Reqwest(err));
-        }
-        on_send();
+        let request_id = sent
+            .headers
+            .get("x-request-id")
+            .and_then(|id| id.to_str().ok())
+            .map(str::to_owned);
+        let api_error = |code, msg, data| {
+            Error::Api(Box::new(ApiError {
+                code,
+                msg,
+                data,
+                status: Some(sent.status),
+                path: Some(endpoint.clone()),
+                request_id: request_id.clone(),
+                ..Default::default()
+            }))
+        };
+        let body = &sent.body;
 
-        let body = sent.bytes().await?;
-
-        match serde_json::from_slice::<ApiResponse<R::Response>>(&body) {
-            Ok(ApiResponse { code, msg, data }) => match code {
-                Some(0) => {
-                    if let Some(data) = data {
-                        Ok(data)
-                    } else {
-                        Err(Error::Api(ApiError {
-                            code,
-                            msg: Some("Success but empty response".to_owned()),
-                            data: None,
-                            conn_id: None,
-                        }))
-                    }
-                }
-                code => Err(Error::Api(ApiError {
-                    code,
+        if !sent.status.is_success() {
+            // OKX sends its code and msg along with 4xx statuses, e.g. 401 for an invalid signature
+            return match serde_json::from_slice::<ApiResponse<serde_json::Value>>(body) {
+                Ok(ApiResponse {
+                    code: code @ Some(_),
                     msg,
                     data,
-                    conn_id: None,
-                })),
-            },
-            Err(e) => {
-                log::error!("{}", String::from_utf8_lossy(&body));
+                }) => Err(api_error(code, msg, data)),
+                _ => Err(Error::Status {
+                    status: sent.status,
+                    path: endpoint,
+                    request_id,
+                }),
+            };
+        }
+        on_send();
+
+        let response = serde_json::from_slice::<ApiResponse<R::Response>>(body);
+        if let Ok(ApiResponse {
+            code: Some(0),
+            data,
+            ..
+        }) = response
+        {
+            return data.ok_or_else(|| {
+                api_error(Some(0), Some("Success but empty response".to_owned()), None)
+            });
+        }
+        // the data of failed requests, e.g. the orders of a failed batch, is kept as raw JSON
+        match (
+            response,
+            serde_json::from_slice::<ApiResponse<serde_json::Value>>(body),
+        ) {
+            (_, Ok(ApiResponse { code, msg, data })) if code != Some(0) => {
+                Err(api_error(code, msg, data))
+            }
+            (Ok(ApiResponse { code, msg, .. }), _) => Err(api_error(code, msg, None)),
+            (Err(e), _) => {
+                log::error!("{}", String::from_utf8_lossy(body));
                 Err(Error::Json(e))
             }
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::error::{ErrorCategory, OkxErrorCode};
+    use crate::api::v5::funding::FundsTransfer;
+    use crate::api::v5::market::GetTicker;
+    use crate::api::v5::model::{AccountType, TransferType};
+    use crate::api::v5::order_book::trade::tests::limit_order;
+    use crate::api::v5::order_book::trade::PlaceMultipleOrders;
+    use crate::api::v5::trading::GetTradingBalances;
+    use async_trait::async_trait;
+    use reqwest::StatusCode;
+    use std::collections::VecDeque;
+    use std::convert::TryFrom;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    const TICKER: &str = r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","last":"9999.99","ts":"1597026383085"}]}"#;
+    const RATE_LIMITED: &str = r#"{"code":"50011","msg":"Too Many Requests","data":[]}"#;
+
+    #[derive(Clone, Default)]
+    pub(crate) struct MockTransport {
+        responses: Arc<Mutex<VecDeque<(StatusCode, &'static str)>>>,
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl MockTransport {
+        pub(crate) fn new(responses: impl IntoIterator<Item = (StatusCode, &'static str)>) -> Self {
+            Self {
+                responses: Arc::new(Mutex::new(responses.into_iter().collect())),
+                requests: Default::default(),
+            }
+        }
+
+        pub(crate) fn requests(&self) -> Vec<HttpRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected request");
+            let mut headers = HeaderMap::new();
+            headers.insert("x-request-id", HeaderValue::from_static("mock-request-id"));
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: body.into(),
+            })
+        }
+    }
+
+    fn ticker() -> GetTicker {
+        GetTicker {
+            inst_id: "BTC-USDT".into(),
+        }
+    }
+
+    pub(crate) fn rest(transport: &MockTransport) -> Rest {
+        let options = Options::new_with_credential(LiveTrading, "key", "secret", "passphrase")
+            .with_retry(RetryPolicy {
+                initial_backoff: Duration::ZERO,
+                ..Default::default()
+            });
+        Rest::with_transport(options, transport.clone())
+    }
+
+    #[tokio::test]
+    async fn test_signs_private_requests() {
+        let transport = MockTransport::new([(StatusCode::OK, r#"{"code":"0","data":[]}"#)]);
+        let balances = rest(&transport)
+            .request(GetTradingBalances::default())
+            .await
+            .unwrap();
+        assert!(balances.is_empty());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].url.path(), "/api/v5/account/balance");
+        assert_eq!(requests[0].headers["OK-ACCESS-KEY"], "key");
+        assert_eq!(requests[0].headers["OK-ACCESS-PASSPHRASE"], "passphrase");
+        assert!(requests[0].headers.contains_key("OK-ACCESS-SIGN"));
+        assert!(requests[0].headers.contains_key("OK-ACCESS-TIMESTAMP"));
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limited_requests() {
+        let transport = MockTransport::new([
+            (StatusCode::OK, RATE_LIMITED),
+            (StatusCode::SERVICE_UNAVAILABLE, ""),
+            (StatusCode::OK, TICKER),
+        ]);
+        let tickers = rest(&transport).request(ticker()).await.unwrap();
+        assert_eq!(tickers[0].ts, Some(1597026383085));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let transport = MockTransport::new([(StatusCode::BAD_GATEWAY, ""); 4]);
+        let err = rest(&transport).request(ticker()).await.unwrap_err();
+        match err {
+            Error::Retry { retries, last } => {
+                assert_eq!(retries, 3);
+                assert!(matches!(
+                    *last,
+                    Error::Status {
+                        status: StatusCode::BAD_GATEWAY,
+                        ..
+                    }
+                ));
+                assert_eq!(last.path(), Some("/api/v5/market/ticker"));
+                assert_eq!(last.request_id(), Some("mock-request-id"));
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+        assert_eq!(transport.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_parses_error_body_of_failed_status() {
+        let transport = MockTransport::new([
+            (
+                StatusCode::UNAUTHORIZED,
+                r#"{"msg":"Invalid Sign","code":"50113"}"#,
+            ),
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                r#"{"msg":"Too Many Requests","code":"50011"}"#,
+            ),
+            (StatusCode::OK, TICKER),
+        ]);
+        let err = rest(&transport)
+            .request(GetTradingBalances::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+        assert_eq!(err.request_id(), Some("mock-request-id"));
+        match &err {
+            Error::Api(err) => {
+                assert_eq!(err.error_code(), Some(OkxErrorCode::InvalidSignature));
+                assert_eq!(err.msg.as_deref(), Some("Invalid Sign"));
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+        assert!(!err.is_retryable());
+
+        let tickers = rest(&transport).request(ticker()).await.unwrap();
+        assert_eq!(tickers.len(), 1);
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_non_idempotent_requests() {
+        let transport = MockTransport::new([(StatusCode::BAD_GATEWAY, "")]);
+        let transfer = FundsTransfer {
+            r#type: TransferType::WithinAccount,
+            ccy: "USDT".to_owned(),
+            amt: Some(1.0),
+            from: AccountType::Funding,
+            to: AccountType::Trading,
+            sub_acct: None,
+            client_id: None,
+        };
+        let err = rest(&transport).request(transfer).await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_takes_one_token_per_order() {
+        let transport =
+            MockTransport::new([(StatusCode::OK, r#"{"code":"0","msg":"","data":[]}"#)]);
+        let rest = Rest::with_transport(
+            rest(&transport)
+                .options()
+                .clone()
+                .with_rate_limit(RateLimitMode::FailFast),
+            transport.clone(),
+        );
+        let orders: Vec<_> = (0..20).map(|i| limit_order(&i.to_string())).collect();
+        let orders = PlaceMultipleOrders::try_from(orders).unwrap();
+        assert_eq!(orders.rate_limit_cost(), 20);
+        rest.request(orders).await.unwrap();
+
+        let limit = PlaceMultipleOrders::RATE_LIMIT.unwrap();
+        let path = PlaceMultipleOrders::PATH;
+        assert!(rest
+            .rate_limiter
+            .try_acquire(path, None, &limit, 281)
+            .is_err());
+        assert!(rest
+            .rate_limiter
+            .try_acquire(path, None, &limit, 280)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_request_batch() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"2","msg":"","data":[{"clOrdId":"a","ordId":"12345689","tag":"","ts":"1695190491421","sCode":"0","sMsg":""},{"clOrdId":"b","ordId":"","tag":"","ts":"1695190491421","sCode":"51008","sMsg":"Order failed. Insufficient balance."}]}"#,
+        )]);
+        let orders =
+            PlaceMultipleOrders::try_from(vec![limit_order("a"), limit_order("b")]).unwrap();
+        let results = rest(&transport).request_batch(orders).await.unwrap();
+        assert_eq!(
+            results[0].as_ref().unwrap().ord_id.as_deref(),
+            Some("12345689")
+        );
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.code, OkxErrorCode::InsufficientBalance);
+        assert_eq!(err.code.category(), ErrorCategory::InsufficientBalance);
+        assert_eq!(err.msg, "Order failed. Insufficient balance.");
+
+        let transport = MockTransport::new([(
+            StatusCode::UNAUTHORIZED,
+            r#"{"code":"50113","msg":"Invalid Sign","data":[]}"#,
+        )]);
+        let orders = PlaceMultipleOrders::try_from(vec![limit_order("a")]).unwrap();
+        match rest(&transport).request_batch(orders).await.unwrap_err() {
+            Error::Api(err) => assert_eq!(
+                err.error_code().map(OkxErrorCode::category),
+                Some(ErrorCategory::Auth)
+            ),
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use super::{RateLimitMode, RetryPolicy, WsConfig};
+
 pub trait OKXEnv {
     fn rest(&self) -> &str;
     fn public_websocket(&self) -> &str;
@@ -62,6 +64,9 @@ pub struct Options {
     pub api_key: Option<String>,
     pub secret_key: Option<String>,
     pub passphrase: Option<String>,
+    pub retry: Option<RetryPolicy>,
+    pub rate_limit: Option<RateLimitMode>,
+    pub websocket: WsConfig,
 }
 
 impl Options {
@@ -71,6 +76,9 @@ impl Options {
             api_key: None,
             secret_key: None,
             passphrase: None,
+            retry: None,
+            rate_limit: None,
+            websocket: WsConfig::default(),
         }
     }
 
@@ -85,8 +93,29 @@ impl Options {
             api_key: Some(api_key.as_ref().to_string()),
             secret_key: Some(secret_key.as_ref().to_string()),
             passphrase: Some(passphrase.as_ref().to_string()),
+            retry: None,
+            rate_limit: None,
+            websocket: WsConfig::default(),
         }
     }
+
+    /// Retry transient failures of idempotent requests with the given policy
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Enforce the documented endpoint rate limits client side before sending
+    pub fn with_rate_limit(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit = Some(mode);
+        self
+    }
+
+    /// Heartbeat and reconnection settings of the WebSocket clients
+    pub fn with_websocket(mut self, websocket: WsConfig) -> Self {
+        self.websocket = websocket;
+        self
+    }
 }
 
 impl Options {
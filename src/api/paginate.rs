@@ -0,0 +1,131 @@
+use crate::api::error::Error;
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimitMode, Rest};
+
+use futures::{stream, Stream, TryStreamExt};
+
+impl Rest {
+    /// Walks a history endpoint back through time, one page after another,
+    /// by setting the `after` cursor of `req` to the oldest item of the previous page.
+    ///
+    /// The stream ends when the server returns an empty or partial page, or at the first item older than
+    /// `until` (Unix timestamp in milliseconds). Pages are paced by the endpoint rate limit,
+    /// even when [`Options::rate_limit`](crate::api::Options::rate_limit) is not set.
+    pub fn paginate<R>(
+        &self,
+        req: R,
+        until: Option<u64>,
+    ) -> impl Stream<Item = Result<R::Item, Error>> + '_
+    where
+        R: Paginated + 'static,
+    {
+        stream::try_unfold(Some(req), move |req| async move {
+            let Some(mut req) = req else {
+                return Ok::<_, Error>(None);
+            };
+            self.pace(&req).await;
+
+            let page = self.request(req.clone()).await?;
+            if page.is_empty() {
+                return Ok(None);
+            }
+            let full = page.len() >= req.limit().unwrap_or(R::DEFAULT_LIMIT);
+            let cursor = page.last().and_then(R::cursor);
+
+            let len = page.len();
+            let items: Vec<_> = page
+                .into_iter()
+                .take_while(|item| match (until, R::timestamp(item)) {
+                    (Some(until), Some(ts)) => ts >= until,
+                    _ => true,
+                })
+                .collect();
+
+            let next = match cursor {
+                Some(cursor) if full && items.len() == len => {
+                    req.set_after(cursor);
+                    Some(req)
+                }
+                _ => None,
+            };
+            Ok(Some((stream::iter(items.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+    }
+
+    /// Waits for the endpoint rate limit, unless already enforced by [`Rest::request`]
+    async fn pace<R: Request>(&self, req: &R) {
+        if self.options.rate_limit.is_some() {
+            return;
+        }
+        if let Some(limit) = R::RATE_LIMIT {
+            let _ = self
+                .rate_limiter
+                .acquire(
+                    R::PATH,
+                    req.rate_limit_key(),
+                    &limit,
+                    req.rate_limit_cost(),
+                    RateLimitMode::Wait,
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::market::GetHistoryCandlesticks;
+    use futures::TryStreamExt;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_paginates_until_time_bound() {
+        let transport = MockTransport::new([
+            (
+                StatusCode::OK,
+                r#"{"code":"0","data":[["400","1","1","1","1","1","1","1","1"],["300","1","1","1","1","1","1","1","1"]]}"#,
+            ),
+            (
+                StatusCode::OK,
+                r#"{"code":"0","data":[["200","1","1","1","1","1","1","1","1"],["100","1","1","1","1","1","1","1","1"]]}"#,
+            ),
+        ]);
+        let rest = rest(&transport);
+        let req = GetHistoryCandlesticks {
+            inst_id: "BTC-USDT".to_owned(),
+            limit: Some(2),
+            ..Default::default()
+        };
+        let candles: Vec<_> = rest.paginate(req, Some(150)).try_collect().await.unwrap();
+
+        let ts: Vec<_> = candles.iter().map(|candle| candle.ts.unwrap()).collect();
+        assert_eq!(ts, [400, 300, 200]);
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].url.query(),
+            Some("instId=BTC-USDT&after=300&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginates_until_partial_page() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","data":[["400","1","1","1","1","1","1","1","1"]]}"#,
+        )]);
+        let req = GetHistoryCandlesticks {
+            inst_id: "BTC-USDT".to_owned(),
+            ..Default::default()
+        };
+        let candles: Vec<_> = rest(&transport)
+            .paginate(req, None)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(candles.len(), 1);
+        assert_eq!(transport.requests().len(), 1);
+    }
+}
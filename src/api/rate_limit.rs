@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Rule OKX uses to group requests into one rate limit bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitRule {
+    /// IP
+    Ip,
+    /// IP + Instrument Type
+    IpInstrumentType,
+    /// IP + Instrument ID
+    IpInstrumentId,
+    /// IP + Underlying
+    IpUnderlying,
+    /// UserID
+    UserId,
+    /// UserID + InstrumentID
+    UserIdInstrumentId,
+    /// UserID + Instrument Family
+    UserIdInstrumentFamily,
+    /// UserID + Currency
+    UserIdCurrency,
+}
+
+impl RateLimitRule {
+    /// Whether requests are bucketed by a key besides the user or IP, see [`Request::rate_limit_key`](super::v5::Request::rate_limit_key)
+    pub fn is_keyed(&self) -> bool {
+        !matches!(self, Self::Ip | Self::UserId)
+    }
+}
+
+/// Rate limit of an endpoint: `count` requests per `window`, bucketed by `rule`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub count: u32,
+    pub window: Duration,
+    pub rule: RateLimitRule,
+}
+
+impl RateLimit {
+    pub const fn new(count: u32, window: Duration, rule: RateLimitRule) -> Self {
+        Self {
+            count,
+            window,
+            rule,
+        }
+    }
+}
+
+/// What [`Rest`](super::Rest) does when a request would exceed its endpoint's rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// Wait until the bucket has a free token
+    #[default]
+    Wait,
+    /// Return [`Error::RateLimited`](super::error::Error::RateLimited) without sending
+    FailFast,
+}
+
+/// Bucket key used for `UserID + InstrumentID` endpoints.
+/// Options are limited per instrument family, e.g. `BTC-USD-240628-60000-C` shares the `BTC-USD` bucket.
+pub(crate) fn instrument_bucket(inst_id: &str) -> String {
+    let parts: Vec<&str> = inst_id.split('-').collect();
+    match parts.as_slice() {
+        [base, quote, _, _, "C" | "P"] => format!("{base}-{quote}"),
+        _ => inst_id.to_owned(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BucketKey {
+    path: &'static str,
+    key: Option<String>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: &RateLimit, now: Instant) -> Self {
+        let capacity = f64::from(limit.count.max(1));
+        Self {
+            tokens: capacity,
+            capacity,
+            refill_per_sec: capacity / limit.window.as_secs_f64(),
+            updated: now,
+        }
+    }
+
+    /// Takes `cost` tokens, or returns how long to wait until they are available.
+    /// A cost above the capacity takes the whole bucket.
+    fn try_acquire(&mut self, now: Instant, cost: u32) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated = now;
+
+        let cost = f64::from(cost).min(self.capacity);
+        if self.tokens >= cost {
+            self.tokens -= cost;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (cost - self.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+/// Token buckets shared by every clone of a [`Rest`](super::Rest) client
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<BucketKey, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// Takes `cost` tokens from the bucket of `path`/`key`, or returns how long to wait until they are available.
+    /// `key` is ignored when the rule of `limit` is not keyed.
+    pub fn try_acquire(
+        &self,
+        path: &'static str,
+        key: Option<String>,
+        limit: &RateLimit,
+        cost: u32,
+    ) -> Result<(), Duration> {
+        let key = key.filter(|_| limit.rule.is_keyed());
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        buckets
+            .entry(BucketKey { path, key })
+            .or_insert_with(|| TokenBucket::new(limit, now))
+            .try_acquire(now, cost)
+    }
+
+    /// Takes `cost` tokens, waiting for them in [`RateLimitMode::Wait`]
+    /// or returning the required wait in [`RateLimitMode::FailFast`]
+    pub async fn acquire(
+        &self,
+        path: &'static str,
+        key: Option<String>,
+        limit: &RateLimit,
+        cost: u32,
+        mode: RateLimitMode,
+    ) -> Result<(), Duration> {
+        loop {
+            match self.try_acquire(path, key.clone(), limit, cost) {
+                Ok(()) => return Ok(()),
+                Err(wait) if mode == RateLimitMode::Wait => {
+                    log::debug!("{path} rate limited, waiting {wait:?}");
+                    tokio::time::sleep(wait).await;
+                }
+                Err(wait) => return Err(wait),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: RateLimit =
+        RateLimit::new(2, Duration::from_secs(2), RateLimitRule::UserIdInstrumentId);
+
+    #[test]
+    fn test_bucket_refills() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(&LIMIT, start);
+        assert!(bucket.try_acquire(start, 1).is_ok());
+        assert!(bucket.try_acquire(start, 1).is_ok());
+        assert_eq!(bucket.try_acquire(start, 1), Err(Duration::from_secs(1)));
+
+        let later = start + Duration::from_secs(1);
+        assert!(bucket.try_acquire(later, 1).is_ok());
+        assert!(bucket.try_acquire(later, 1).is_err());
+    }
+
+    #[test]
+    fn test_buckets_are_keyed() {
+        let limiter = RateLimiter::default();
+        for _ in 0..2 {
+            assert!(limiter
+                .try_acquire("/trade/order", Some("BTC-USDT".into()), &LIMIT, 1)
+                .is_ok());
+        }
+        assert!(limiter
+            .try_acquire("/trade/order", Some("BTC-USDT".into()), &LIMIT, 1)
+            .is_err());
+        assert!(limiter
+            .try_acquire("/trade/order", Some("ETH-USDT".into()), &LIMIT, 1)
+            .is_ok());
+        assert!(limiter
+            .clone()
+            .try_acquire("/trade/order", Some("BTC-USDT".into()), &LIMIT, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_batch_cost() {
+        let limit = RateLimit::new(
+            300,
+            Duration::from_secs(2),
+            RateLimitRule::UserIdInstrumentId,
+        );
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(&limit, start);
+        assert!(bucket.try_acquire(start, 20).is_ok());
+        assert_eq!(bucket.tokens, 280.0);
+        for _ in 0..14 {
+            assert!(bucket.try_acquire(start, 20).is_ok());
+        }
+        assert!(bucket.try_acquire(start, 20).is_err());
+
+        let mut bucket = TokenBucket::new(&LIMIT, start);
+        assert!(bucket.try_acquire(start, 20).is_ok());
+        assert!(bucket.try_acquire(start, 1).is_err());
+    }
+
+    #[test]
+    fn test_unkeyed_rule_ignores_key() {
+        let limit = RateLimit::new(1, Duration::from_secs(2), RateLimitRule::UserId);
+        let limiter = RateLimiter::default();
+        assert!(limiter
+            .try_acquire("/account/balance", Some("BTC".into()), &limit, 1)
+            .is_ok());
+        assert!(limiter
+            .try_acquire("/account/balance", Some("ETH".into()), &limit, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_instrument_bucket() {
+        assert_eq!(instrument_bucket("BTC-USDT"), "BTC-USDT");
+        assert_eq!(instrument_bucket("BTC-USD-SWAP"), "BTC-USD-SWAP");
+        assert_eq!(instrument_bucket("BTC-USD-240628-60000-C"), "BTC-USD");
+    }
+}
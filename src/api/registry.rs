@@ -0,0 +1,422 @@
+use crate::api::error::Error;
+use crate::api::v5::model::{
+    Instrument, InstrumentStatus, InstrumentType, OrderType, QuantityType, Side, TradeMode,
+};
+use crate::api::v5::order_book::trade::PlaceOrder;
+use crate::api::v5::public_data::rest::GetInstruments;
+use crate::api::Rest;
+use crate::serde_util::{Number, OrderNumber};
+
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Instrument types loaded without an underlying, options are listed per underlying
+const INSTRUMENT_TYPES: [InstrumentType; 4] = [
+    InstrumentType::Spot,
+    InstrumentType::Margin,
+    InstrumentType::Swap,
+    InstrumentType::Futures,
+];
+
+/// Reason a [`PlaceOrder`] would be rejected by the exchange, see [`InstrumentRegistry::validate`]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("unknown instrument {0}")]
+    UnknownInstrument(String),
+
+    #[error("instrument {inst_id} is {state}, not live")]
+    NotLive {
+        inst_id: String,
+        state: InstrumentStatus,
+    },
+
+    #[error("invalid number {0}")]
+    InvalidNumber(String),
+
+    #[error("price {px} is not a multiple of tick size {tick_sz}")]
+    TickSize { px: Decimal, tick_sz: Decimal },
+
+    #[error("size {sz} is not a multiple of lot size {lot_sz}")]
+    LotSize { sz: Decimal, lot_sz: Decimal },
+
+    #[error("size {sz} is below minimum size {min_sz}")]
+    BelowMinSize { sz: Decimal, min_sz: Decimal },
+
+    #[error("size {sz} is above maximum size {max_sz}")]
+    AboveMaxSize { sz: Decimal, max_sz: Decimal },
+}
+
+/// Instruments indexed by instrument type and ID, loaded with [`GetInstruments`].
+/// SPOT and MARGIN list the same IDs, e.g. `BTC-USDT`, with their own state.
+///
+/// Options are only listed per underlying, they are loaded for the underlyings given to
+/// [`InstrumentRegistry::with_option_underlyings`].
+#[derive(Debug, Default)]
+pub struct InstrumentRegistry {
+    option_underlyings: Vec<String>,
+    instruments: RwLock<HashMap<(InstrumentType, String), Instrument>>,
+}
+
+impl InstrumentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also loads the options of these underlyings, e.g. `BTC-USD`
+    pub fn with_option_underlyings<I, S>(mut self, underlyings: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.option_underlyings = underlyings.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Reloads every instrument type, the registry is left unchanged on error
+    pub async fn refresh(&self, rest: &Rest) -> Result<(), Error> {
+        let requests = INSTRUMENT_TYPES
+            .into_iter()
+            .map(|inst_type| GetInstruments {
+                inst_type,
+                uly: None,
+                inst_family: None,
+                inst_id: None,
+            })
+            .chain(self.option_underlyings.iter().map(|uly| GetInstruments {
+                inst_type: InstrumentType::Option,
+                uly: Some(uly.clone()),
+                inst_family: None,
+                inst_id: None,
+            }));
+
+        let mut instruments = HashMap::new();
+        for req in requests {
+            for instrument in rest.request(req).await? {
+                instruments.insert(
+                    (instrument.inst_type, instrument.inst_id.clone()),
+                    instrument,
+                );
+            }
+        }
+        *self.instruments.write().unwrap() = instruments;
+        Ok(())
+    }
+
+    /// Refreshes the registry every `period`, forever. Failures are logged and retried at the next period.
+    pub async fn refresh_every(&self, rest: &Rest, period: Duration) {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.refresh(rest).await {
+                log::warn!("failed to refresh instruments: {}", err);
+            }
+        }
+    }
+
+    /// Adds or replaces an instrument
+    pub fn insert(&self, instrument: Instrument) {
+        self.instruments.write().unwrap().insert(
+            (instrument.inst_type, instrument.inst_id.clone()),
+            instrument,
+        );
+    }
+
+    pub fn get(&self, inst_type: InstrumentType, inst_id: &str) -> Option<Instrument> {
+        self.instruments
+            .read()
+            .unwrap()
+            .get(&(inst_type, inst_id.to_owned()))
+            .cloned()
+    }
+
+    /// Instrument of any type, SPOT before MARGIN, whose tick and lot sizes are the same
+    fn find(&self, inst_id: &str) -> Option<Instrument> {
+        INSTRUMENT_TYPES
+            .into_iter()
+            .chain([InstrumentType::Option])
+            .find_map(|inst_type| self.get(inst_type, inst_id))
+    }
+
+    /// Instrument an order trades: SPOT for cash orders, MARGIN for margin orders on currency pairs
+    fn for_order(&self, order: &PlaceOrder) -> Option<Instrument> {
+        let inst_type = match order.td_mode {
+            TradeMode::Cash => InstrumentType::Spot,
+            TradeMode::Cross | TradeMode::Isolated => InstrumentType::Margin,
+        };
+        self.get(inst_type, &order.inst_id)
+            .or_else(|| self.find(&order.inst_id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.instruments.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Rounds `px` to the nearest multiple of the tick size,
+    /// `None` if the instrument is unknown or has no tick size
+    pub fn round_price(&self, inst_id: &str, px: Number) -> Option<Number> {
+        let tick_sz = self.find(inst_id)?.tick_size.and_then(to_decimal)?;
+        let px = to_decimal(px)?;
+        from_decimal((px / tick_sz).round() * tick_sz)
+    }
+
+    /// Rounds `sz` down to a multiple of the lot size, so that it never exceeds the requested size,
+    /// `None` if the instrument is unknown or has no lot size
+    pub fn round_size(&self, inst_id: &str, sz: Number) -> Option<Number> {
+        let lot_sz = self.find(inst_id)?.lot_size.and_then(to_decimal)?;
+        let sz = to_decimal(sz)?;
+        from_decimal((sz / lot_sz).floor() * lot_sz)
+    }
+
+    /// Checks the order against the instrument rules: instrument live, price aligned to the tick size,
+    /// size aligned to the lot size and within the minimum and maximum order sizes.
+    ///
+    /// Cash orders on currency pairs are checked against the SPOT instrument, margin orders against MARGIN.
+    /// Sizes of SPOT market orders in quote currency are not checked.
+    pub fn validate(&self, order: &PlaceOrder) -> Result<(), OrderValidationError> {
+        let instrument = self
+            .for_order(order)
+            .ok_or_else(|| OrderValidationError::UnknownInstrument(order.inst_id.to_string()))?;
+        if instrument.status != InstrumentStatus::Live {
+            return Err(OrderValidationError::NotLive {
+                inst_id: instrument.inst_id,
+                state: instrument.status,
+            });
+        }
+
+        let market = matches!(
+            order.ord_type,
+            OrderType::Market | OrderType::OptimalLimitIoc
+        );
+        if let (Some(px), false) = (&order.px, market) {
+            let px = parse(px)?;
+            if let Some(tick_sz) = instrument.tick_size.and_then(to_decimal) {
+                if !(px % tick_sz).is_zero() {
+                    return Err(OrderValidationError::TickSize { px, tick_sz });
+                }
+            }
+        }
+
+        let quote_sz = matches!(instrument.inst_type, InstrumentType::Spot)
+            && matches!(order.ord_type, OrderType::Market)
+            && match order.tgt_ccy {
+                Some(QuantityType::QuoteCcy) => true,
+                Some(_) => false,
+                None => order.side == Side::Buy,
+            };
+        if quote_sz {
+            return Ok(());
+        }
+
+        let sz = parse(&order.sz)?;
+        if let Some(lot_sz) = instrument.lot_size.and_then(to_decimal) {
+            if !(sz % lot_sz).is_zero() {
+                return Err(OrderValidationError::LotSize { sz, lot_sz });
+            }
+        }
+        if let Some(min_sz) = instrument.min_size.and_then(to_decimal) {
+            if sz < min_sz {
+                return Err(OrderValidationError::BelowMinSize { sz, min_sz });
+            }
+        }
+        let max_sz = if market {
+            instrument.max_mkt_size
+        } else {
+            instrument.max_lmt_size
+        };
+        if let Some(max_sz) = max_sz.and_then(to_decimal) {
+            if sz > max_sz {
+                return Err(OrderValidationError::AboveMaxSize { sz, max_sz });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse(n: &OrderNumber) -> Result<Decimal, OrderValidationError> {
+    let n = n.to_string();
+    Decimal::from_str(&n).map_err(|_| OrderValidationError::InvalidNumber(n))
+}
+
+#[cfg(not(feature = "decimal"))]
+fn to_decimal(n: Number) -> Option<Decimal> {
+    Decimal::from_str(&n.to_string()).ok()
+}
+
+#[cfg(feature = "decimal")]
+fn to_decimal(n: Number) -> Option<Decimal> {
+    Some(n)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn from_decimal(n: Decimal) -> Option<Number> {
+    n.normalize().to_string().parse().ok()
+}
+
+#[cfg(feature = "decimal")]
+fn from_decimal(n: Decimal) -> Option<Number> {
+    Some(n.normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::ApiResponse;
+    use reqwest::StatusCode;
+
+    const INSTRUMENTS: &str = r#"{"code":"0","msg":"","data":[{"instType":"SPOT","instId":"BTC-USDT","uly":"","category":"1","baseCcy":"BTC","quoteCcy":"USDT","settleCcy":"","ctVal":"","ctMult":"","ctValCcy":"","optType":"","stk":"","listTime":"1606468572000","expTime":"","lever":"10","tickSz":"0.1","lotSz":"0.00000001","minSz":"0.00001","ctType":"","alias":"","state":"live","maxLmtSz":"9999999999","maxMktSz":"1000000","maxTwapSz":"","maxIcebergSz":"","maxTriggerSz":"","maxStopSz":""}]}"#;
+    /// BTC-USDT suspended for margin trading only
+    const MARGIN_INSTRUMENTS: &str = r#"{"code":"0","msg":"","data":[{"instType":"MARGIN","instId":"BTC-USDT","uly":"","category":"1","baseCcy":"BTC","quoteCcy":"USDT","settleCcy":"","ctVal":"","ctMult":"","ctValCcy":"","optType":"","stk":"","listTime":"1606468572000","expTime":"","lever":"10","tickSz":"0.1","lotSz":"0.00000001","minSz":"0.00001","ctType":"","alias":"","state":"suspend","maxLmtSz":"9999999999","maxMktSz":"1000000","maxTwapSz":"","maxIcebergSz":"","maxTriggerSz":"","maxStopSz":""}]}"#;
+
+    fn registry() -> InstrumentRegistry {
+        let response: ApiResponse<Vec<Instrument>> = serde_json::from_str(INSTRUMENTS).unwrap();
+        let registry = InstrumentRegistry::new();
+        for instrument in response.data.unwrap() {
+            registry.insert(instrument);
+        }
+        registry
+    }
+
+    fn order(ord_type: OrderType, sz: &str, px: Option<&str>) -> PlaceOrder {
+        PlaceOrder {
+            inst_id: "BTC-USDT".into(),
+            td_mode: TradeMode::Cash,
+            ccy: None,
+            cl_ord_id: None,
+            tag: None,
+            side: Side::Buy,
+            pos_side: None,
+            ord_type,
+            sz: sz.parse().unwrap(),
+            px: px.map(|px| px.parse().unwrap()),
+            reduce_only: None,
+            tgt_ccy: Some(QuantityType::BaseCcy),
+            ban_amend: None,
+            attach_algo_cl_ord_id: None,
+            tp_trigger_px: None,
+            tp_ord_px: None,
+            sl_trigger_px: None,
+            sl_ord_px: None,
+            tp_trigger_px_type: None,
+            sl_trigger_px_type: None,
+            quick_mgn_type: None,
+            stp_id: None,
+            stp_mode: None,
+        }
+    }
+
+    fn number(n: &str) -> Number {
+        n.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_refresh() {
+        let empty = r#"{"code":"0","msg":"","data":[]}"#;
+        let transport = MockTransport::new([
+            (StatusCode::OK, INSTRUMENTS),
+            (StatusCode::OK, empty),
+            (StatusCode::OK, empty),
+            (StatusCode::OK, empty),
+            (StatusCode::OK, empty),
+        ]);
+        let registry = InstrumentRegistry::new().with_option_underlyings(["BTC-USD"]);
+        registry.refresh(&rest(&transport)).await.unwrap();
+
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get(InstrumentType::Spot, "BTC-USDT").is_some());
+        assert!(registry.get(InstrumentType::Margin, "BTC-USDT").is_none());
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 5);
+        assert_eq!(requests[4].url.query(), Some("instType=OPTION&uly=BTC-USD"));
+    }
+
+    #[tokio::test]
+    async fn test_spot_and_margin() {
+        let empty = r#"{"code":"0","msg":"","data":[]}"#;
+        let transport = MockTransport::new([
+            (StatusCode::OK, INSTRUMENTS),
+            (StatusCode::OK, MARGIN_INSTRUMENTS),
+            (StatusCode::OK, empty),
+            (StatusCode::OK, empty),
+        ]);
+        let registry = InstrumentRegistry::new();
+        registry.refresh(&rest(&transport)).await.unwrap();
+        assert_eq!(registry.len(), 2);
+        assert!(matches!(
+            registry.get(InstrumentType::Spot, "BTC-USDT"),
+            Some(Instrument {
+                inst_type: InstrumentType::Spot,
+                status: InstrumentStatus::Live,
+                ..
+            })
+        ));
+
+        // cash market buy in quote currency, checked against the live SPOT instrument
+        let mut quote = order(OrderType::Market, "100", None);
+        quote.tgt_ccy = None;
+        assert_eq!(registry.validate(&quote), Ok(()));
+
+        let mut margin_order = order(OrderType::Limit, "0.01", Some("41006.3"));
+        margin_order.td_mode = TradeMode::Cross;
+        assert!(matches!(
+            registry.validate(&margin_order),
+            Err(OrderValidationError::NotLive { .. })
+        ));
+    }
+
+    #[test]
+    fn test_round() {
+        let registry = registry();
+        assert_eq!(
+            registry.round_price("BTC-USDT", number("41006.26")),
+            Some(number("41006.3"))
+        );
+        assert_eq!(
+            registry.round_size("BTC-USDT", number("0.123456789")),
+            Some(number("0.12345678"))
+        );
+        assert_eq!(registry.round_price("ETH-USDT", number("1")), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let registry = registry();
+        assert_eq!(
+            registry.validate(&order(OrderType::Limit, "0.01", Some("41006.3"))),
+            Ok(())
+        );
+        assert!(matches!(
+            registry.validate(&order(OrderType::Limit, "0.01", Some("41006.35"))),
+            Err(OrderValidationError::TickSize { .. })
+        ));
+        assert!(matches!(
+            registry.validate(&order(OrderType::Limit, "0.000001", Some("41006.3"))),
+            Err(OrderValidationError::BelowMinSize { .. })
+        ));
+        assert!(matches!(
+            registry.validate(&order(OrderType::Market, "1000001", None)),
+            Err(OrderValidationError::AboveMaxSize { .. })
+        ));
+
+        let mut quote = order(OrderType::Market, "100.5", None);
+        quote.tgt_ccy = None;
+        assert_eq!(registry.validate(&quote), Ok(()));
+
+        let mut unknown = order(OrderType::Limit, "1", Some("1"));
+        unknown.inst_id = "ETH-USDT".into();
+        assert_eq!(
+            registry.validate(&unknown),
+            Err(OrderValidationError::UnknownInstrument(
+                "ETH-USDT".to_owned()
+            ))
+        );
+    }
+}
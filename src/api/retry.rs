@@ -0,0 +1,87 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Retry policy applied by [`Rest`](super::Rest) to transient failures:
+/// timeouts, connection errors, 5xx responses and the OKX rate limit / system busy codes.
+///
+/// Only idempotent requests are retried, see [`Request::is_idempotent`](super::v5::Request::is_idempotent).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between two attempts
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after every retry
+    pub multiplier: f64,
+    /// Fraction of the delay that is randomised, `0.0` disables jitter
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    /// Delay to wait before the `retry`-th retry (starting at 0)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let base = self.initial_backoff.as_secs_f64() * self.multiplier.powi(retry.min(64) as i32);
+        let base = base.min(self.max_backoff.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = if jitter > 0.0 {
+            rand::thread_rng().gen_range(1.0 - jitter..=1.0)
+        } else {
+            1.0
+        };
+        Duration::from_secs_f64(base * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(200));
+        assert_eq!(policy.backoff(1), Duration::from_millis(400));
+        assert_eq!(policy.backoff(2), Duration::from_millis(800));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_backoff_jitter_within_bounds() {
+        let policy = RetryPolicy::default();
+        for retry in 0..5 {
+            let upper = RetryPolicy {
+                jitter: 0.0,
+                ..Default::default()
+            }
+            .backoff(retry);
+            let delay = policy.backoff(retry);
+            assert!(delay <= upper);
+            assert!(delay >= upper / 2);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, ClientBuilder, Method, StatusCode, Url};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Signed HTTP request handed to a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Raw HTTP response returned by a [`Transport`], parsed by [`Rest`](super::Rest)
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+#[derive(Debug, Error)]
+pub enum TransportError {
+    #[error("request timed out")]
+    Timeout,
+
+    #[error("connection failed: {0}")]
+    Connect(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl TransportError {
+    /// Whether the request never reached the exchange or timed out waiting for it
+    pub fn is_transient(&self) -> bool {
+        match self {
+            TransportError::Timeout | TransportError::Connect(_) => true,
+            TransportError::Reqwest(err) => err.is_timeout() || err.is_connect(),
+            TransportError::Other(_) => false,
+        }
+    }
+}
+
+/// Sends signed requests for [`Rest`](super::Rest).
+///
+/// Signing and response parsing stay in `Rest`, a transport only moves bytes.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError>;
+}
+
+/// Default [`Transport`] backed by a [`reqwest::Client`]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        let client = ClientBuilder::new()
+            .tcp_nodelay(true)
+            .tcp_keepalive(Duration::from_secs(30))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+
+        Self::new(client)
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let sent = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers)
+            .body(request.body)
+            .send()
+            .await?;
+
+        let status = sent.status();
+        let headers = sent.headers().clone();
+        let body = sent.bytes().await?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
@@ -1,10 +1,17 @@
 use crate::api::v5::model::FundingBalance;
-use crate::api::v5::model::{AccountType, FundTransferResponse, TransferType};
-use crate::api::v5::Request;
+use crate::api::v5::model::{
+    AccountType, AssetValuation, CancelWithdrawalResponse, CurrencyInfo, DepositAddress,
+    DepositRecord, DepositState, FundTransferResponse, FundTransferState, FundingBill,
+    FundingBillType, TransferType, WithdrawalDestination, WithdrawalRecord, WithdrawalResponse,
+    WithdrawalState,
+};
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
 use crate::serde_util::MaybeFloat;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 use super::*;
 
@@ -29,6 +36,11 @@ impl Request for GetFundingBalances {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/asset/balances";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
     type Response = Vec<FundingBalance>;
 }
 
@@ -76,6 +88,601 @@ impl Request for FundsTransfer {
     const METHOD: Method = Method::POST;
     const PATH: &'static str = "/asset/transfer";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        1,
+        Duration::from_secs(1),
+        RateLimitRule::UserIdCurrency,
+    ));
 
     type Response = Vec<FundTransferResponse>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(self.ccy.clone())
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-funds-transfer-state
+/// ## Get funds transfer state
+/// Retrieve the transfer state data of the last 2 weeks.
+///
+/// Rate Limit: 10 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/transfer-state
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFundsTransferState {
+    /// Transfer ID, either `trans_id` or `client_id` is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trans_id: Option<String>,
+    /// Client-supplied ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Transfer type, the default is 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<TransferType>,
+}
+
+impl Request for GetFundsTransferState {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/transfer-state";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<FundTransferState>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-currencies
+/// ## Get currencies
+/// Retrieve a list of all currencies available which are related to the current account's KYC entity.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/currencies
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrencies {
+    /// Single currency or multiple currencies separated with comma, e.g. BTC or BTC,ETH.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+}
+
+impl Request for GetCurrencies {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/currencies";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<CurrencyInfo>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-deposit-address
+/// ## Get deposit address
+/// Retrieve the deposit addresses of currencies, including previously-used addresses.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/deposit-address
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepositAddress {
+    /// Currency, e.g. BTC
+    pub ccy: String,
+}
+
+impl Request for GetDepositAddress {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/deposit-address";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<DepositAddress>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-deposit-history
+/// ## Get deposit history
+/// Retrieve the deposit records according to the currency, deposit status, and time range in reverse chronological order.
+/// The 100 most recent records are returned by default.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/deposit-history
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepositHistory {
+    /// Currency, e.g. BTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Deposit ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dep_id: Option<String>,
+    /// Internal transfer initiator's withdrawal ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_wd_id: Option<String>,
+    /// Hash record of the deposit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_id: Option<String>,
+    /// Deposit type, `Internal`: internal transfer, `OnChain`: deposit from chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<WithdrawalDestination>,
+    /// Status of deposit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<DepositState>,
+    /// Pagination of data to return records earlier than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetDepositHistory {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/deposit-history";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<DepositRecord>;
+}
+
+impl Paginated for GetDepositHistory {
+    type Item = DepositRecord;
+    type Cursor = u64;
+
+    fn cursor(item: &DepositRecord) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &DepositRecord) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-withdrawal
+/// ## Withdrawal
+/// Only supported withdrawal of assets from funding account. Common sub-account does not support withdrawal.
+///
+/// The API can only make withdrawal to verified addresses, and verified addresses can be set by WEB/APP.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **POST** /api/v5/asset/withdrawal
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Withdrawal {
+    /// Currency, e.g. USDT
+    pub ccy: String,
+    /// Withdrawal amount
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub amt: f64,
+    /// Withdrawal method
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub dest: WithdrawalDestination,
+    /// Verified digital currency address, email or mobile number.
+    /// Some digital currency addresses are formatted as `address:tag`, e.g. `ARDOR-7JF3-8F2E-QUWZ-CAN7F:123456`
+    pub to_addr: String,
+    /// Chain name, e.g. USDT-ERC20, USDT-TRC20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// Area code for the phone number, required if `to_addr` is a phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_code: Option<String>,
+    /// Client-supplied ID
+    /// A combination of case-sensitive alphanumerics, all numbers, or all letters of up to 32 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+}
+
+impl Request for Withdrawal {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/asset/withdrawal";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<WithdrawalResponse>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-cancel-withdrawal
+/// ## Cancel withdrawal
+/// You can cancel normal withdrawal requests, but you cannot cancel withdrawal requests on Lightning.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **POST** /api/v5/asset/cancel-withdrawal
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelWithdrawal {
+    /// Withdrawal ID
+    pub wd_id: String,
+}
+
+impl Request for CancelWithdrawal {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/asset/cancel-withdrawal";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<CancelWithdrawalResponse>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-withdrawal-history
+/// ## Get withdrawal history
+/// Retrieve the withdrawal records according to the currency, withdrawal status, and time range in reverse chronological order.
+/// The 100 most recent records are returned by default.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/withdrawal-history
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWithdrawalHistory {
+    /// Currency, e.g. BTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Withdrawal ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wd_id: Option<String>,
+    /// Client-supplied ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Hash record of the withdrawal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_id: Option<String>,
+    /// Withdrawal type, `Internal`: internal transfer, `OnChain`: on-chain withdrawal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<WithdrawalDestination>,
+    /// Status of withdrawal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<WithdrawalState>,
+    /// Pagination of data to return records earlier than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetWithdrawalHistory {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/withdrawal-history";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<WithdrawalRecord>;
+}
+
+impl Paginated for GetWithdrawalHistory {
+    type Item = WithdrawalRecord;
+    type Cursor = u64;
+
+    fn cursor(item: &WithdrawalRecord) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &WithdrawalRecord) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-get-account-asset-valuation
+/// ## Get account asset valuation
+/// View account asset valuation
+///
+/// Rate Limit: 1 request per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/asset-valuation
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAssetValuation {
+    /// Asset valuation calculation unit, e.g. BTC, USDT, USD. The default is the valuation in BTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+}
+
+impl Request for GetAssetValuation {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/asset-valuation";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        1,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<AssetValuation>;
+}
+
+/// https://www.okx.com/docs-v5/en/#funding-account-rest-api-asset-bills-details
+/// ## Asset bills details
+/// Query the billing record. You can get the latest 1 month historical data.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/bills
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFundingBills {
+    /// Currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Bill type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<FundingBillType>,
+    /// Client-supplied ID for transfer or withdrawal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Pagination of data to return records earlier than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetFundingBills {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/bills";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<FundingBill>;
+}
+
+impl Paginated for GetFundingBills {
+    type Item = FundingBill;
+    type Cursor = u64;
+
+    fn cursor(item: &FundingBill) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &FundingBill) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::model::TransferState;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_funds_transfer_state() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"amt":"1.5","ccy":"USDT","clientId":"","from":"18","instId":"","state":"success","subAcct":"test","to":"6","toInstId":"","transId":"1","type":"1"}]}"#,
+        )]);
+        let state = rest(&transport)
+            .request(GetFundsTransferState {
+                trans_id: Some("1".to_owned()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(matches!(state[0].state, Some(TransferState::Success)));
+        assert!(matches!(state[0].from, Some(AccountType::Trading)));
+        assert!(matches!(state[0].to, Some(AccountType::Funding)));
+        assert_eq!(transport.requests()[0].url.query(), Some("transId=1"));
+    }
+
+    #[tokio::test]
+    async fn test_get_currencies() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"canDep":true,"canInternal":false,"canWd":true,"ccy":"BTC","chain":"BTC-Bitcoin","mainNet":true,"maxFee":"0.0004","minDep":"0.00005","minFee":"0.0002","minWd":"0.0005","name":"Bitcoin","needTag":false,"wdTickSz":"8"}]}"#,
+        )]);
+        let currencies = rest(&transport)
+            .request(GetCurrencies::default())
+            .await
+            .unwrap();
+        assert_eq!(currencies[0].can_dep, Some(true));
+        assert_eq!(currencies[0].can_internal, Some(false));
+        assert_eq!(currencies[0].wd_tick_sz, Some(8));
+    }
+
+    #[tokio::test]
+    async fn test_get_deposit_address() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"chain":"BTC-Bitcoin","ctAddr":"","ccy":"BTC","to":"6","addr":"39XNxK1Ryqgg3Bsyn6HzoqV4Xji25pNkv6","verifiedName":"","selected":true}]}"#,
+        )]);
+        let addresses = rest(&transport)
+            .request(GetDepositAddress {
+                ccy: "BTC".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            addresses[0].addr.as_deref(),
+            Some("39XNxK1Ryqgg3Bsyn6HzoqV4Xji25pNkv6")
+        );
+        assert_eq!(addresses[0].selected, Some(true));
+        assert!(matches!(addresses[0].to, Some(AccountType::Funding)));
+    }
+
+    #[tokio::test]
+    async fn test_get_deposit_history() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"actualDepBlkConfirm":"2","amt":"1","areaCodeFrom":"","ccy":"USDT","chain":"USDT-TRC20","depId":"88888888","from":"","fromWdId":"","state":"2","to":"TN4hGjVXMzy4ZxhchS8qQAwNjV6kdbfP6m","ts":"1652676958000","txId":"b5fd37f7c8e4ab1f7c3acc1e6f5aeb8f81ee0da29bd4d5bdb0c6e5b15d13e9b4"}]}"#,
+        )]);
+        let deposits = rest(&transport)
+            .request(GetDepositHistory {
+                r#type: Some(WithdrawalDestination::OnChain),
+                state: Some(DepositState::Successful),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(deposits[0].dep_id.as_deref(), Some("88888888"));
+        assert_eq!(deposits[0].actual_dep_blk_confirm, Some(2));
+        assert!(matches!(deposits[0].state, Some(DepositState::Successful)));
+        assert_eq!(transport.requests()[0].url.query(), Some("type=4&state=2"));
+    }
+
+    #[tokio::test]
+    async fn test_withdrawal() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"amt":"0.1","wdId":"67485","ccy":"BTC","clientId":"","chain":"BTC-Bitcoin"}]}"#,
+        )]);
+        let withdrawal = rest(&transport)
+            .request(Withdrawal {
+                ccy: "BTC".to_owned(),
+                amt: 0.1,
+                dest: WithdrawalDestination::OnChain,
+                to_addr: "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_owned(),
+                chain: Some("BTC-Bitcoin".to_owned()),
+                area_code: None,
+                client_id: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(withdrawal[0].wd_id.as_deref(), Some("67485"));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"ccy":"BTC","amt":"0.1","dest":"4","toAddr":"bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh","chain":"BTC-Bitcoin"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_withdrawal() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"wdId":"1123456"}]}"#,
+        )]);
+        let canceled = rest(&transport)
+            .request(CancelWithdrawal {
+                wd_id: "1123456".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(canceled[0].wd_id.as_deref(), Some("1123456"));
+        assert_eq!(transport.requests()[0].body, r#"{"wdId":"1123456"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_get_withdrawal_history() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"chain":"ETH-Ethereum","fee":"0.007","feeCcy":"ETH","ccy":"ETH","clientId":"","amt":"0.029809","txId":"0x35c","from":"156****1234","areaCodeFrom":"86","to":"0xa30","areaCodeTo":"","state":"2","ts":"1655251200000","nonTradableAsset":false,"wdId":"15447421"}]}"#,
+        )]);
+        let withdrawals = rest(&transport)
+            .request(GetWithdrawalHistory {
+                r#type: Some(WithdrawalDestination::Internal),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(withdrawals[0].wd_id.as_deref(), Some("15447421"));
+        assert_eq!(withdrawals[0].fee, Some(0.007));
+        assert_eq!(withdrawals[0].non_tradable_asset, Some(false));
+        assert_eq!(withdrawals[0].ts, Some(1655251200000));
+        assert_eq!(transport.requests()[0].url.query(), Some("type=3"));
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_valuation() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"details":{"classic":"124.6","earn":"1122.73","funding":"0.09","trading":"2544.28"},"totalBal":"3790.09","ts":"1637566660769"},{"totalBal":"0","ts":"1637566660769"}]}"#,
+        )]);
+        let valuations = rest(&transport)
+            .request(GetAssetValuation {
+                ccy: Some("USDT".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(valuations[0].total_bal, Some(3790.09));
+        let details = valuations[0].details.as_ref().unwrap();
+        assert_eq!(details.trading, Some(2544.28));
+        assert!(valuations[1].details.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_funding_bills() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"billId":"12344","ccy":"BTC","clientId":"","balChg":"2","bal":"12","type":"1","ts":"1597026383085"}]}"#,
+        )]);
+        let bills = rest(&transport)
+            .request(GetFundingBills {
+                r#type: Some(FundingBillType::Deposit),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(bills[0].bill_id.as_deref(), Some("12344"));
+        assert_eq!(bills[0].bal_chg, Some(2.0));
+        assert!(matches!(bills[0].r#type, Some(FundingBillType::Deposit)));
+        assert_eq!(GetFundingBills::cursor(&bills[0]), Some(1597026383085));
+        assert_eq!(transport.requests()[0].url.query(), Some("type=1&limit=1"));
+    }
 }
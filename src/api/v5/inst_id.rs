@@ -0,0 +1,355 @@
+use crate::api::v5::model::{InstrumentType, OptionType};
+use crate::impl_serde_from_str;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Date format of FUTURES and OPTION expiries, e.g. `250328`
+const EXPIRY_FORMAT: &str = "%y%m%d";
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("invalid instrument ID {0}")]
+pub struct InvalidInstId(pub String);
+
+/// Instrument ID, serialized as the exchange string:
+/// - SPOT/MARGIN `BTC-USDT`
+/// - SWAP `BTC-USD-SWAP`
+/// - FUTURES `BTC-USD-250328`
+/// - OPTION `BTC-USD-250328-60000-C`
+///
+/// Request structs take an [`InstIdOrStr`], use `inst_id.into()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InstId {
+    Spot {
+        base: String,
+        quote: String,
+    },
+    Swap {
+        base: String,
+        quote: String,
+    },
+    Futures {
+        base: String,
+        quote: String,
+        expiry: NaiveDate,
+    },
+    Option {
+        base: String,
+        quote: String,
+        expiry: NaiveDate,
+        strike: Decimal,
+        option_type: OptionType,
+    },
+}
+
+impl InstId {
+    pub fn base(&self) -> &str {
+        match self {
+            Self::Spot { base, .. }
+            | Self::Swap { base, .. }
+            | Self::Futures { base, .. }
+            | Self::Option { base, .. } => base,
+        }
+    }
+
+    pub fn quote(&self) -> &str {
+        match self {
+            Self::Spot { quote, .. }
+            | Self::Swap { quote, .. }
+            | Self::Futures { quote, .. }
+            | Self::Option { quote, .. } => quote,
+        }
+    }
+
+    /// Underlying of derivatives, or the currency pair of SPOT, e.g. `BTC-USD`
+    pub fn underlying(&self) -> String {
+        format!("{}-{}", self.base(), self.quote())
+    }
+
+    /// Expiry date of FUTURES and OPTION
+    pub fn expiry(&self) -> Option<NaiveDate> {
+        match self {
+            Self::Futures { expiry, .. } | Self::Option { expiry, .. } => Some(*expiry),
+            _ => None,
+        }
+    }
+
+    /// Strike price of OPTION
+    pub fn strike(&self) -> Option<Decimal> {
+        match self {
+            Self::Option { strike, .. } => Some(*strike),
+            _ => None,
+        }
+    }
+
+    pub fn option_type(&self) -> Option<OptionType> {
+        match self {
+            Self::Option { option_type, .. } => Some(*option_type),
+            _ => None,
+        }
+    }
+
+    /// Instrument type, `SPOT` for currency pairs, which are also traded as `MARGIN`
+    pub fn inst_type(&self) -> InstrumentType {
+        match self {
+            Self::Spot { .. } => InstrumentType::Spot,
+            Self::Swap { .. } => InstrumentType::Swap,
+            Self::Futures { .. } => InstrumentType::Futures,
+            Self::Option { .. } => InstrumentType::Option,
+        }
+    }
+}
+
+impl Display for InstId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.base(), self.quote())?;
+        match self {
+            Self::Spot { .. } => Ok(()),
+            Self::Swap { .. } => write!(f, "-SWAP"),
+            Self::Futures { expiry, .. } => write!(f, "-{}", expiry.format(EXPIRY_FORMAT)),
+            Self::Option {
+                expiry,
+                strike,
+                option_type,
+                ..
+            } => write!(
+                f,
+                "-{}-{}-{}",
+                expiry.format(EXPIRY_FORMAT),
+                strike,
+                option_type
+            ),
+        }
+    }
+}
+
+impl FromStr for InstId {
+    type Err = InvalidInstId;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidInstId(s.to_owned());
+        let expiry = |date: &str| {
+            if date.len() != 6 {
+                return Err(invalid());
+            }
+            NaiveDate::parse_from_str(date, EXPIRY_FORMAT).map_err(|_| invalid())
+        };
+
+        let parts: Vec<_> = s.split('-').collect();
+        if parts.iter().any(|part| part.is_empty()) {
+            return Err(invalid());
+        }
+        let (base, quote) = match parts.as_slice() {
+            [base, quote, ..] => (base.to_string(), quote.to_string()),
+            _ => return Err(invalid()),
+        };
+        match parts[2..] {
+            [] => Ok(Self::Spot { base, quote }),
+            ["SWAP"] => Ok(Self::Swap { base, quote }),
+            [date] => Ok(Self::Futures {
+                base,
+                quote,
+                expiry: expiry(date)?,
+            }),
+            [date, strike, option_type] => Ok(Self::Option {
+                base,
+                quote,
+                expiry: expiry(date)?,
+                strike: strike.parse().map_err(|_| invalid())?,
+                option_type: option_type.parse().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl_serde_from_str!(InstId);
+
+impl From<InstId> for String {
+    fn from(inst_id: InstId) -> Self {
+        inst_id.to_string()
+    }
+}
+
+/// Instrument ID field of request structs, built from a parsed [`InstId`] or a raw exchange string,
+/// serialized as the exchange string either way
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InstIdOrStr(String);
+
+impl InstIdOrStr {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn parse(&self) -> Result<InstId, InvalidInstId> {
+        self.0.parse()
+    }
+}
+
+impl Deref for InstIdOrStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for InstIdOrStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<InstId> for InstIdOrStr {
+    fn from(inst_id: InstId) -> Self {
+        Self(inst_id.to_string())
+    }
+}
+
+impl From<&InstId> for InstIdOrStr {
+    fn from(inst_id: &InstId) -> Self {
+        Self(inst_id.to_string())
+    }
+}
+
+impl From<String> for InstIdOrStr {
+    fn from(inst_id: String) -> Self {
+        Self(inst_id)
+    }
+}
+
+impl From<&str> for InstIdOrStr {
+    fn from(inst_id: &str) -> Self {
+        Self(inst_id.to_owned())
+    }
+}
+
+impl From<InstIdOrStr> for String {
+    fn from(inst_id: InstIdOrStr) -> Self {
+        inst_id.0
+    }
+}
+
+impl PartialEq<str> for InstIdOrStr {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for InstIdOrStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::v5::market::GetTicker;
+    use crate::api::v5::model::{OrderType, Side, TradeMode};
+    use crate::api::v5::order_book::trade::PlaceOrder;
+
+    #[test]
+    fn test_round_trip() {
+        for s in [
+            "BTC-USDT",
+            "BTC-USD-SWAP",
+            "BTC-USD-250328",
+            "BTC-USD-250328-60000-C",
+            "ETH-USD-250328-2500.5-P",
+        ] {
+            let inst_id: InstId = s.parse().unwrap();
+            assert_eq!(inst_id.to_string(), s);
+            assert_eq!(
+                serde_json::to_string(&inst_id).unwrap(),
+                format!("\"{}\"", s)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        let inst_id: InstId = serde_json::from_str("\"BTC-USD-250328-60000-C\"").unwrap();
+        assert_eq!(inst_id.base(), "BTC");
+        assert_eq!(inst_id.quote(), "USD");
+        assert_eq!(inst_id.underlying(), "BTC-USD");
+        assert_eq!(inst_id.expiry(), NaiveDate::from_ymd_opt(2025, 3, 28));
+        assert_eq!(inst_id.strike(), Some(Decimal::new(60000, 0)));
+        assert_eq!(inst_id.option_type(), Some(OptionType::Call));
+        assert_eq!(inst_id.inst_type(), InstrumentType::Option);
+
+        let inst_id: InstId = "BTC-USDT".parse().unwrap();
+        assert_eq!(inst_id.inst_type(), InstrumentType::Spot);
+        assert_eq!(inst_id.expiry(), None);
+        let req = GetTicker {
+            inst_id: inst_id.into(),
+        };
+        assert_eq!(serde_qs::to_string(&req).unwrap(), "instId=BTC-USDT");
+    }
+
+    #[test]
+    fn test_request_fields() {
+        let parsed: InstId = "BTC-USD-250328-60000-C".parse().unwrap();
+        let raw = GetTicker {
+            inst_id: "BTC-USD-250328-60000-C".into(),
+        };
+        let typed = GetTicker {
+            inst_id: parsed.clone().into(),
+        };
+        assert_eq!(raw.inst_id, typed.inst_id);
+        assert_eq!(
+            serde_qs::to_string(&raw).unwrap(),
+            serde_qs::to_string(&typed).unwrap()
+        );
+        assert_eq!(typed.inst_id.parse(), Ok(parsed.clone()));
+
+        let order = |inst_id: InstIdOrStr| PlaceOrder {
+            inst_id,
+            td_mode: TradeMode::Cash,
+            ccy: None,
+            cl_ord_id: None,
+            tag: None,
+            side: Side::Buy,
+            pos_side: None,
+            ord_type: OrderType::Limit,
+            sz: "1".parse().unwrap(),
+            px: Some("0.5".parse().unwrap()),
+            reduce_only: None,
+            tgt_ccy: None,
+            ban_amend: None,
+            attach_algo_cl_ord_id: None,
+            tp_trigger_px: None,
+            tp_ord_px: None,
+            sl_trigger_px: None,
+            sl_ord_px: None,
+            tp_trigger_px_type: None,
+            sl_trigger_px_type: None,
+            quick_mgn_type: None,
+            stp_id: None,
+            stp_mode: None,
+        };
+        let raw = serde_json::to_string(&order(String::from("BTC-USD-250328-60000-C").into()));
+        let typed = serde_json::to_string(&order(parsed.into()));
+        assert_eq!(raw.unwrap(), typed.unwrap());
+    }
+
+    #[test]
+    fn test_invalid() {
+        for s in [
+            "BTC",
+            "BTC-USD-PERP",
+            "BTC-USD-2503",
+            "BTC-USD-251332",
+            "BTC-USD-250328-60000-X",
+            "BTC--USDT",
+        ] {
+            assert_eq!(s.parse::<InstId>(), Err(InvalidInstId(s.to_owned())));
+        }
+    }
+}
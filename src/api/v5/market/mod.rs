@@ -1,9 +1,15 @@
 use model::Platform24Volume;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
-use crate::api::v5::model::{Candle, InstrumentType, Ticker};
-use crate::api::v5::Request;
+use crate::api::v5::inst_id::InstIdOrStr;
+use crate::api::v5::model::{
+    BookUpdate, Candle, CandleOHLC, IndexComponents, IndexTicker, InstrumentType,
+    OptionFamilyTrades, Ticker, Trade,
+};
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
 
 use super::*;
 
@@ -30,6 +36,11 @@ impl Request for GetTickers {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/market/tickers";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
 
     type Response = Vec<Ticker>;
 }
@@ -47,13 +58,18 @@ impl Request for GetTickers {
 #[serde(rename_all = "camelCase")]
 pub struct GetTicker {
     /// Instrument ID, e.g. BTC-USD-SWAP
-    pub inst_id: String,
+    pub inst_id: InstIdOrStr,
 }
 
 impl Request for GetTicker {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/market/ticker";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
 
     type Response = Vec<Ticker>;
 }
@@ -93,6 +109,11 @@ impl Request for GetCandlesticks {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/market/candles";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
 
     type Response = Vec<Candle>;
 }
@@ -132,10 +153,36 @@ impl Request for GetHistoryCandlesticks {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/market/history-candles";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
 
     type Response = Vec<Candle>;
 }
 
+impl Paginated for GetHistoryCandlesticks {
+    type Item = Candle;
+    type Cursor = u64;
+
+    fn cursor(item: &Candle) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &Candle) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
 /// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-24h-total-volume
 /// ## Get Platform 24 hours volume
 /// The 24-hour trading volume is calculated on a rolling basis.
@@ -153,6 +200,477 @@ impl Request for GetPlatform24Volume {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/market/platform-24-volume";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
 
     type Response = Vec<Platform24Volume>;
 }
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-order-book
+/// ## Get order book
+/// Retrieve order book of the instrument.
+///
+/// Rate Limit: 40 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/books
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderBook {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Order book depth per side. Maximum 400, e.g. 400 bids + 400 asks
+    /// Default returns to 1 depth data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sz: Option<usize>,
+}
+
+impl Request for GetOrderBook {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/books";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        40,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<BookUpdate>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-full-order-book
+/// ## Get full order book
+/// Retrieve order book of the instrument. The data will be updated once a second.
+///
+/// Rate Limit: 10 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/books-full
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFullOrderBook {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Order book depth per side. Maximum 5000, e.g. 5000 bids + 5000 asks
+    /// Default returns to 1 depth data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sz: Option<usize>,
+}
+
+impl Request for GetFullOrderBook {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/books-full";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<BookUpdate>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-trades
+/// ## Get trades
+/// Retrieve the recent transactions of an instrument.
+///
+/// Rate Limit: 100 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/trades
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTrades {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Number of results per request. The maximum is 500; The default is 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetTrades {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/trades";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        100,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<Trade>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-trades-history
+/// ## Get trades history
+/// Retrieve the recent transactions of an instrument from the last 3 months with pagination.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/history-trades
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHistoryTrades {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Pagination Type
+    /// 1: tradeId 2: timestamp
+    /// The default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<u8>,
+    /// Pagination of data to return records earlier than the requested tradeId or ts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested tradeId.
+    /// Do not support timestamp for pagination. The latest data will be returned when using before individually
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Number of results per request. The maximum and default both are 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetHistoryTrades {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/history-trades";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<Trade>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-option-trades-by-instrument-family
+/// ## Get option trades by instrument family
+/// Retrieve the recent transactions of an instrument under same instFamily. The maximum is 100.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/option/instrument-family-trades
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOptionFamilyTrades {
+    /// Instrument family, e.g. BTC-USD
+    /// Applicable to OPTION
+    pub inst_family: String,
+}
+
+impl Request for GetOptionFamilyTrades {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/option/instrument-family-trades";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<OptionFamilyTrades>;
+}
+
+/// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-index-tickers
+/// ## Get index tickers
+/// Retrieve index tickers.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/index-tickers
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIndexTickers {
+    /// Quote currency
+    /// Currently there is only an index with USD/USDT/BTC/USDC as the quote currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_ccy: Option<String>,
+    /// Index, e.g. BTC-USD
+    /// Either quoteCcy or instId is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+}
+
+impl Request for GetIndexTickers {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/index-tickers";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<IndexTicker>;
+}
+
+/// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-index-candlesticks
+/// ## Get index candlesticks
+/// Retrieve the candlestick charts of the index. This endpoint can retrieve the latest 1,440 data entries. Charts are returned in groups based on the requested bar.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/index-candles
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIndexCandles {
+    /// Index, e.g. BTC-USD
+    pub inst_id: String,
+    /// Pagination of data to return records earlier than the requested ts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested ts. The latest data will be returned when using before individually
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Bar size, the default is 1m
+    /// e.g. [1m/3m/5m/15m/30m/1H/2H/4H]
+    /// Hong Kong time opening price k-line：[6H/12H/1D/1W/1M/3M]
+    /// UTC time opening price k-line：[6Hutc/12Hutc/1Dutc/1Wutc/1Mutc/3Mutc]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bar: Option<String>,
+    /// Number of results per request. The maximum is 100; The default is 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetIndexCandles {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/index-candles";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = Vec<CandleOHLC>;
+}
+
+/// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-index-components
+/// ## Get index components
+/// Get the index component information data on the market
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: IP
+///
+/// ### HTTP Request
+/// **GET** /api/v5/market/index-components
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIndexComponents {
+    /// index, e.g BTC-USDT
+    pub index: String,
+}
+
+impl Request for GetIndexComponents {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/market/index-components";
+    const AUTH: bool = false;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::Ip,
+    ));
+
+    type Response = IndexComponents;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::model::{CandleState, OptionType, Side};
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_order_book() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"asks":[["41006.8","0.60038921","0","1"]],"bids":[["41006.3","0.30178218","0","2"]],"ts":"1629966436396"}]}"#,
+        )]);
+        let books = rest(&transport)
+            .request(GetOrderBook {
+                inst_id: "BTC-USDT".to_owned(),
+                sz: Some(1),
+            })
+            .await
+            .unwrap();
+        assert_eq!(books[0].asks[0].px, "41006.8");
+        assert_eq!(books[0].asks[0].sz, "0.60038921");
+        assert_eq!(books[0].bids[0].ord_count, Some(2));
+        assert_eq!(books[0].ts, Some(1629966436396));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT&sz=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_full_order_book() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"asks":[["41006.8","0.60038921","1"]],"bids":[["41006.3","0.30178218","2"]],"ts":"1629966436396"}]}"#,
+        )]);
+        let books = rest(&transport)
+            .request(GetFullOrderBook {
+                inst_id: "BTC-USDT".into(),
+                sz: Some(1),
+            })
+            .await
+            .unwrap();
+        assert_eq!(books[0].asks[0].px, "41006.8");
+        assert_eq!(books[0].bids[0].ord_count, Some(2));
+        let request = &transport.requests()[0];
+        assert_eq!(request.url.query(), Some("instId=BTC-USDT&sz=1"));
+        assert!(!request.headers.contains_key("OK-ACCESS-SIGN"));
+    }
+
+    #[tokio::test]
+    async fn test_get_trades() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","side":"sell","sz":"0.00001","px":"29963.2","tradeId":"242720720","ts":"1654161646974"},{"instId":"BTC-USDT","side":"buy","sz":"0.00002","px":"29963.3","tradeId":"242720719","ts":"1654161641568"}]}"#,
+        )]);
+        let trades = rest(&transport)
+            .request(GetTrades {
+                inst_id: "BTC-USDT".to_owned(),
+                limit: Some(2),
+            })
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].trade_id.as_deref(), Some("242720720"));
+        assert_eq!(trades[0].side, Some(Side::Sell));
+        assert_eq!(trades[0].px, Some(29963.2));
+        assert_eq!(trades[1].ts, Some(1654161641568));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_history_trades() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","side":"buy","sz":"0.00001","px":"29963.2","tradeId":"242720718","ts":"1654161641568"}]}"#,
+        )]);
+        let trades = rest(&transport)
+            .request(GetHistoryTrades {
+                inst_id: "BTC-USDT".to_owned(),
+                r#type: Some(1),
+                after: Some("242720719".to_owned()),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(trades[0].trade_id.as_deref(), Some("242720718"));
+        assert_eq!(trades[0].sz, Some(0.00001));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT&type=1&after=242720719&limit=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_option_family_trades() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"vol24h":"103381","tradeInfo":[{"instId":"BTC-USD-221111-17750-C","side":"sell","sz":"1","px":"0.0075","tradeId":"20","ts":"1668090715058"}],"optType":"C"}]}"#,
+        )]);
+        let trades = rest(&transport)
+            .request(GetOptionFamilyTrades {
+                inst_family: "BTC-USD".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(trades[0].vol_24h, Some(103381.0));
+        assert_eq!(trades[0].opt_type, Some(OptionType::Call));
+        assert_eq!(
+            trades[0].trade_info[0].inst_id.as_deref(),
+            Some("BTC-USD-221111-17750-C")
+        );
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instFamily=BTC-USD")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_index_tickers() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","idxPx":"43350","high24h":"43649.7","sodUtc0":"43444.1","open24h":"43640.8","low24h":"43261.9","sodUtc8":"43328.7","ts":"1649419644492"}]}"#,
+        )]);
+        let tickers = rest(&transport)
+            .request(GetIndexTickers {
+                quote_ccy: None,
+                inst_id: Some("BTC-USDT".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(tickers[0].idx_px, Some(43350.0));
+        assert_eq!(tickers[0].high_24h, Some(43649.7));
+        assert_eq!(tickers[0].sod_utc_0, Some(43444.1));
+        assert_eq!(tickers[0].ts, Some(1649419644492));
+        assert_eq!(transport.requests()[0].url.query(), Some("instId=BTC-USDT"));
+    }
+
+    #[tokio::test]
+    async fn test_get_index_candles() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[["1597026383085","3.721","3.743","3.677","3.708","1"],["1597026383085","3.731","3.799","3.494","3.72","0"]]}"#,
+        )]);
+        let candles = rest(&transport)
+            .request(GetIndexCandles {
+                inst_id: "BTC-USD".to_owned(),
+                bar: Some("1H".to_owned()),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(candles[0].ts, Some(1597026383085));
+        assert_eq!(candles[0].open, Some(3.721));
+        assert_eq!(candles[0].close, Some(3.708));
+        assert_eq!(candles[0].confirm, Some(CandleState::Completed));
+        assert_eq!(candles[1].confirm, Some(CandleState::Uncompleted));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USD&bar=1H&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_index_components() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":{"components":[{"symbol":"BTC/USDT","symPx":"52733.2","wgt":"0.25","cnvPx":"52733.2","exch":"OKEx"}],"last":"52735.4123234925","index":"BTC-USDT","ts":"1630985335599"}}"#,
+        )]);
+        let index = rest(&transport)
+            .request(GetIndexComponents {
+                index: "BTC-USDT".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(index.components[0].exch.as_deref(), Some("OKEx"));
+        assert_eq!(index.ts, Some(1630985335599));
+        assert_eq!(transport.requests()[0].url.query(), Some("index=BTC-USDT"));
+    }
+}
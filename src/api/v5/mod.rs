@@ -1,3 +1,5 @@
+use crate::api::error::{ItemError, OkxErrorCode};
+use crate::api::RateLimit;
 use crate::serde_util::str_opt;
 use std::{borrow::Cow, fmt::Debug};
 
@@ -5,23 +7,102 @@ use reqwest::Method;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub mod funding;
+pub mod inst_id;
 pub mod market;
 pub mod model;
 pub mod order_book;
 pub mod public_data;
+pub mod sub_account;
 pub mod trading;
+pub mod ws;
 
 pub trait Request: Serialize {
     const METHOD: Method;
     const PATH: &'static str;
     const AUTH: bool = false;
+    /// Rate limit documented for the endpoint, enforced client side by [`Rest`](crate::api::Rest)
+    const RATE_LIMIT: Option<RateLimit> = None;
 
     type Response: DeserializeOwned + Debug;
 
     fn path(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::PATH)
     }
+
+    /// Whether sending the request twice has the same effect as sending it once.
+    /// Only idempotent requests are retried by [`RetryPolicy`](crate::api::RetryPolicy).
+    fn is_idempotent(&self) -> bool {
+        Self::METHOD == Method::GET
+    }
+
+    /// Distinguishes the rate limit buckets of one endpoint,
+    /// e.g. the instrument ID for `UserID + InstrumentID` rules
+    fn rate_limit_key(&self) -> Option<String> {
+        None
+    }
+
+    /// Number of tokens the request takes from its rate limit bucket,
+    /// e.g. the number of orders of a batch limited to "300 orders per 2 seconds"
+    fn rate_limit_cost(&self) -> u32 {
+        1
+    }
+}
+
+/// Request of a history endpoint returning the newest records first, paginated with an `after` cursor,
+/// see [`Rest::paginate`](crate::api::Rest::paginate)
+pub trait Paginated: Request<Response = Vec<Self::Item>> + Clone {
+    type Item: Debug;
+    type Cursor;
+
+    /// Number of results per page when `limit` is not set
+    const DEFAULT_LIMIT: usize = 100;
+
+    /// Cursor of an item, the next page starts after it
+    fn cursor(item: &Self::Item) -> Option<Self::Cursor>;
+
+    /// Timestamp of an item in milliseconds, compared to the time bound of the pagination
+    fn timestamp(item: &Self::Item) -> Option<u64>;
+
+    /// Sets the `after` cursor
+    fn set_after(&mut self, cursor: Self::Cursor);
+
+    /// Number of results per page
+    fn limit(&self) -> Option<usize>;
+}
+
+/// Item of a batch response carrying its own result, `s_code` other than 0 means the item failed.
+/// OKX returns code 0 when every item succeeded, 1 when all failed and 2 when some failed,
+/// see [`Rest::request_batch`](crate::api::Rest::request_batch)
+pub trait BatchItem: Sized {
+    fn s_code(&self) -> Option<u64>;
+
+    fn s_msg(&self) -> Option<&str>;
+
+    fn into_result(self) -> Result<Self, ItemError> {
+        match self.s_code() {
+            None | Some(0) => Ok(self),
+            Some(code) => Err(ItemError {
+                code: OkxErrorCode::from(code),
+                msg: self.s_msg().unwrap_or_default().to_owned(),
+            }),
+        }
+    }
+}
+
+macro_rules! impl_batch_item {
+    ($($ty:ty),+) => {
+        $(impl $crate::api::v5::BatchItem for $ty {
+            fn s_code(&self) -> Option<u64> {
+                self.s_code
+            }
+
+            fn s_msg(&self) -> Option<&str> {
+                self.s_msg.as_deref()
+            }
+        })+
+    };
 }
+pub(crate) use impl_batch_item;
 
 #[derive(Debug, Deserialize)]
 pub struct ApiResponse<T> {
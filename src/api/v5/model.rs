@@ -1,7 +1,9 @@
 use crate::impl_string_enum;
 use crate::serde_util::*;
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -81,6 +83,44 @@ impl_string_enum!(MarginMode,
     Isolated => "isolated",
 );
 
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum PositionMode {
+    LongShort,
+    Net,
+}
+
+impl_string_enum!(PositionMode,
+    LongShort => "long_short_mode",
+    Net => "net_mode",
+);
+
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum MarginBalanceType {
+    Add,
+    Reduce,
+}
+
+impl_string_enum!(MarginBalanceType,
+    Add => "add",
+    Reduce => "reduce",
+);
+
+/// Isolated margin trading settings
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum IsolatedMarginMode {
+    /// Isolated margin with automatic transfers of the margin currency
+    AutoTransfersCcy,
+    /// Isolated margin with automatic transfers
+    Automatic,
+    Other(Unknown),
+}
+
+impl_string_enum!(IsolatedMarginMode,
+    Other,
+    AutoTransfersCcy => "auto_transfers_ccy",
+    Automatic => "automatic",
+);
+
 #[derive(Debug, Clone, Copy, Hash)]
 pub enum TradeMode {
     Cross,
@@ -320,6 +360,170 @@ impl_string_enum!(ExecType,
     Maker => "M",
 );
 
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum AlgoOrderType {
+    Conditional,
+    Oco,
+    Trigger,
+    MoveOrderStop,
+    Iceberg,
+    Twap,
+    Other(Unknown),
+}
+
+impl_string_enum!(AlgoOrderType,
+    Other,
+    Conditional => "conditional",
+    Oco => "oco",
+    Trigger => "trigger",
+    MoveOrderStop => "move_order_stop",
+    Iceberg => "iceberg",
+    Twap => "twap",
+);
+
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum AlgoOrderState {
+    Live,
+    Pause,
+    PartiallyEffective,
+    Effective,
+    Canceled,
+    OrderFailed,
+    PartiallyFailed,
+    Other(Unknown),
+}
+
+impl_string_enum!(AlgoOrderState,
+    Other,
+    Live => "live",
+    Pause => "pause",
+    PartiallyEffective => "partially_effective",
+    Effective => "effective",
+    Canceled => "canceled",
+    OrderFailed => "order_failed",
+    PartiallyFailed => "partially_failed",
+);
+
+/// Bill type of the trading account
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum BillType {
+    Transfer,
+    Trade,
+    Delivery,
+    AutoTokenConversion,
+    Liquidation,
+    MarginTransfer,
+    InterestDeduction,
+    FundingFee,
+    Adl,
+    Clawback,
+    SystemTokenConversion,
+    StrategyTransfer,
+    Ddh,
+    BlockTrade,
+    QuickMargin,
+    Borrowing,
+    Repay,
+    SpreadTrading,
+    StructuredProducts,
+    Convert,
+    EasyConvert,
+    OneClickRepay,
+    SimpleTrade,
+    Loans,
+    Settlement,
+    ProfitSharingExpenses,
+    ProfitSharingRefund,
+    Other(Unknown),
+}
+
+impl_string_enum!(BillType,
+    Other,
+    Transfer => "1",
+    Trade => "2",
+    Delivery => "3",
+    AutoTokenConversion => "4",
+    Liquidation => "5",
+    MarginTransfer => "6",
+    InterestDeduction => "7",
+    FundingFee => "8",
+    Adl => "9",
+    Clawback => "10",
+    SystemTokenConversion => "11",
+    StrategyTransfer => "12",
+    Ddh => "13",
+    BlockTrade => "14",
+    QuickMargin => "15",
+    Borrowing => "16",
+    Repay => "22",
+    SpreadTrading => "24",
+    StructuredProducts => "26",
+    Convert => "27",
+    EasyConvert => "28",
+    OneClickRepay => "29",
+    SimpleTrade => "30",
+    Loans => "33",
+    Settlement => "34",
+    ProfitSharingExpenses => "250",
+    ProfitSharingRefund => "251",
+);
+
+/// Bill subtype of the trading account, most common ones, others are kept as [`BillSubType::Other`]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum BillSubType {
+    Buy,
+    Sell,
+    OpenLong,
+    OpenShort,
+    CloseLong,
+    CloseShort,
+    MarketLoansInterestDeduction,
+    TransferIn,
+    TransferOut,
+    VipLoansInterestDeduction,
+    LiquidationLongToMarketClose,
+    LiquidationShortToMarketClose,
+    LiquidationBuy,
+    LiquidationSell,
+    DeliveryLong,
+    DeliveryShort,
+    DeliveryBuy,
+    DeliverySell,
+    ManualMarginIncrease,
+    ManualMarginDecrease,
+    AutoMarginIncrease,
+    FundingFeeExpense,
+    FundingFeeIncome,
+    Other(Unknown),
+}
+
+impl_string_enum!(BillSubType,
+    Other,
+    Buy => "1",
+    Sell => "2",
+    OpenLong => "3",
+    OpenShort => "4",
+    CloseLong => "5",
+    CloseShort => "6",
+    MarketLoansInterestDeduction => "9",
+    TransferIn => "11",
+    TransferOut => "12",
+    VipLoansInterestDeduction => "14",
+    LiquidationLongToMarketClose => "100",
+    LiquidationShortToMarketClose => "101",
+    LiquidationBuy => "102",
+    LiquidationSell => "103",
+    DeliveryLong => "112",
+    DeliveryShort => "113",
+    DeliveryBuy => "117",
+    DeliverySell => "118",
+    ManualMarginIncrease => "160",
+    ManualMarginDecrease => "161",
+    AutoMarginIncrease => "162",
+    FundingFeeExpense => "173",
+    FundingFeeIncome => "174",
+);
+
 /// Transfer type
 /// 0: transfer within account
 /// 1: master account to sub-account (Only applicable to API Key from master account)
@@ -350,10 +554,145 @@ impl_string_enum!(TransferType,
     SubAccountToSubAccount => "4",
 );
 
+/// State of a funds transfer
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum TransferState {
+    Success,
+    Pending,
+    Failed,
+    Other(Unknown),
+}
+
+impl_string_enum!(TransferState,
+    Other,
+    Success => "success",
+    Pending => "pending",
+    Failed => "failed",
+);
+
+/// Status of a deposit
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum DepositState {
+    /// 0: waiting for confirmation
+    WaitingConfirmation,
+    /// 1: deposit credited
+    Credited,
+    /// 2: deposit successful
+    Successful,
+    /// 8: pending due to temporary deposit suspension on this crypto currency
+    Suspended,
+    /// 11: match the address blacklist
+    Blacklisted,
+    /// 12: account or deposit is frozen
+    Frozen,
+    /// 13: sub-account deposit interception
+    SubAccountIntercepted,
+    /// 14: KYC limit
+    KycLimit,
+    Other(Unknown),
+}
+
+impl_string_enum!(DepositState,
+    Other,
+    WaitingConfirmation => "0",
+    Credited => "1",
+    Successful => "2",
+    Suspended => "8",
+    Blacklisted => "11",
+    Frozen => "12",
+    SubAccountIntercepted => "13",
+    KycLimit => "14",
+);
+
+/// Status of a withdrawal, the manual review states (4, 5, 6, 8, 9, 12) are kept as [`WithdrawalState::Other`]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum WithdrawalState {
+    /// -3: canceling
+    Canceling,
+    /// -2: canceled
+    Canceled,
+    /// -1: failed
+    Failed,
+    /// 0: waiting withdrawal
+    Waiting,
+    /// 1: withdrawing
+    Withdrawing,
+    /// 2: withdraw success
+    Success,
+    /// 7: approved
+    Approved,
+    /// 10: waiting transfer
+    WaitingTransfer,
+    /// 15: pending transaction validation
+    PendingValidation,
+    Other(Unknown),
+}
+
+impl_string_enum!(WithdrawalState,
+    Other,
+    Canceling => "-3",
+    Canceled => "-2",
+    Failed => "-1",
+    Waiting => "0",
+    Withdrawing => "1",
+    Success => "2",
+    Approved => "7",
+    WaitingTransfer => "10",
+    PendingValidation => "15",
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WithdrawalDestination {
+    /// 3: internal transfer
+    Internal,
+    /// 4: on-chain withdrawal
+    OnChain,
+}
+
+impl_string_enum!(WithdrawalDestination,
+    Internal => "3",
+    OnChain => "4",
+);
+
+/// Bill type of the funding account, most common ones, others are kept as [`FundingBillType::Other`]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum FundingBillType {
+    Deposit,
+    Withdrawal,
+    CanceledWithdrawal,
+    TransferToSubAccount,
+    TransferFromSubAccount,
+    TransferOutFromSubToMaster,
+    TransferInFromMasterToSub,
+    ManuallyClaimedAirdrop,
+    SystemReversal,
+    EventReward,
+    TransferFromTradingAccount,
+    TransferToTradingAccount,
+    Other(Unknown),
+}
+
+impl_string_enum!(FundingBillType,
+    Other,
+    Deposit => "1",
+    Withdrawal => "2",
+    CanceledWithdrawal => "13",
+    TransferToSubAccount => "20",
+    TransferFromSubAccount => "21",
+    TransferOutFromSubToMaster => "22",
+    TransferInFromMasterToSub => "23",
+    ManuallyClaimedAirdrop => "28",
+    SystemReversal => "47",
+    EventReward => "48",
+    TransferFromTradingAccount => "130",
+    TransferToTradingAccount => "131",
+);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OKXSystemTime {
     // System time
+    #[serde(default, with = "str_opt")]
     pub ts: MaybeU64,
 }
 
@@ -373,27 +712,27 @@ pub struct Instrument {
     #[serde(rename = "settleCcy", deserialize_with = "deserialize_from_opt_str")]
     pub margin_currency: Option<String>, // Settlement and margin currency; Only applicable to FUTURES/SWAP/OPTION
     #[serde(rename = "ctVal", default, with = "str_opt")]
-    pub face_value: MaybeFloat, // Contract value; Only applicable to FUTURES/SWAP/OPTION
+    pub face_value: MaybeNumber, // Contract value; Only applicable to FUTURES/SWAP/OPTION
     #[serde(rename = "ctMult", default, with = "str_opt")]
-    pub contract_multiplier: MaybeFloat, // Only applicable to FUTURES/SWAP/OPTION
+    pub contract_multiplier: MaybeNumber, // Only applicable to FUTURES/SWAP/OPTION
     #[serde(rename = "ctValCcy", deserialize_with = "deserialize_from_opt_str")]
     pub contract_value_currency: Option<String>, // Only applicable to FUTURES/SWAP/OPTION
     #[serde(rename = "optType", deserialize_with = "deserialize_from_opt_str")]
     pub option_type: Option<OptionType>, // Only applicable to OPTION
     #[serde(rename = "stk", default, with = "str_opt")]
-    pub strike_price: MaybeFloat, // Only applicable to OPTION
+    pub strike_price: MaybeNumber, // Only applicable to OPTION
     #[serde(rename = "listTime", default, with = "str_opt")]
     pub listing_time: MaybeU64,
     #[serde(rename = "expTime", default, with = "str_opt")]
     pub expiry_time: MaybeU64,
     #[serde(rename = "lever", default, with = "str_opt")]
-    pub max_leverage: MaybeFloat, // Only applicable to FUTURES/OPTION; Not applicable to SPOT, OPTION
+    pub max_leverage: MaybeNumber, // Only applicable to FUTURES/OPTION; Not applicable to SPOT, OPTION
     #[serde(rename = "tickSz", default, with = "str_opt")]
-    pub tick_size: MaybeFloat,
+    pub tick_size: MaybeNumber,
     #[serde(rename = "lotSz", default, with = "str_opt")]
-    pub lot_size: MaybeFloat,
+    pub lot_size: MaybeNumber,
     #[serde(rename = "minSz", default, with = "str_opt")]
-    pub min_size: MaybeFloat,
+    pub min_size: MaybeNumber,
     #[serde(rename = "ctType", deserialize_with = "deserialize_from_opt_str")]
     pub contract_type: Option<ContractType>, // Only applicable to FUTURES/SWAP
     #[serde(rename = "alias", deserialize_with = "deserialize_from_opt_str")]
@@ -401,17 +740,17 @@ pub struct Instrument {
     #[serde(rename = "state")]
     pub status: InstrumentStatus,
     #[serde(rename = "maxLmtSz", default, with = "str_opt")]
-    pub max_lmt_size: MaybeFloat, // The maximum order quantity of the contract or spot limit order
+    pub max_lmt_size: MaybeNumber, // The maximum order quantity of the contract or spot limit order
     #[serde(rename = "maxMktSz", default, with = "str_opt")]
-    pub max_mkt_size: MaybeFloat, // The maximum order quantity of the contract or spot market order
+    pub max_mkt_size: MaybeNumber, // The maximum order quantity of the contract or spot market order
     #[serde(rename = "maxTwapSz", default, with = "str_opt")]
-    pub max_twap_size: MaybeFloat, // The maximum order quantity of the contract or spot twap order
+    pub max_twap_size: MaybeNumber, // The maximum order quantity of the contract or spot twap order
     #[serde(rename = "maxIcebergSz", default, with = "str_opt")]
-    pub max_iceberg_size: MaybeFloat, // The maximum order quantity of the contract or spot iceBerg order
+    pub max_iceberg_size: MaybeNumber, // The maximum order quantity of the contract or spot iceBerg order
     #[serde(rename = "maxTriggerSz", default, with = "str_opt")]
-    pub max_trigger_size: MaybeFloat, // The maximum order quantity of the contract or spot trigger order
+    pub max_trigger_size: MaybeNumber, // The maximum order quantity of the contract or spot trigger order
     #[serde(rename = "maxStopSz", default, with = "str_opt")]
-    pub max_stop_size: MaybeFloat, // The maximum order quantity of the contract or spot stop order
+    pub max_stop_size: MaybeNumber, // The maximum order quantity of the contract or spot stop order
 }
 
 // ========== Trading ==========
@@ -423,45 +762,45 @@ pub struct TradingBalanceDetail {
     pub u_time: Option<u64>,
     /// The total amount of equity in USD
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub total_eq: MaybeFloat,
+    pub total_eq: MaybeNumber,
     /// Isolated margin equity in USD
     // Applicable to Single-currency margin and Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub iso_eq: MaybeFloat,
+    pub iso_eq: MaybeNumber,
     /// Adjusted / Effective equity in USD
     /// The net fiat value of the assets in the account that can provide margins for spot, futures, perpetual swap and options under the cross margin mode.
     /// Cause in multi-ccy or PM mode, the asset and margin requirement will all be converted to USD value to process the order check or liquidation.
     /// Due to the volatility of each currency market, our platform calculates the actual USD value of each currency based on discount rates to balance market risks.
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub adj_eq: MaybeFloat,
+    pub adj_eq: MaybeNumber,
     /// Cross margin frozen for pending orders in USD
     /// Only applicable to Multi-currency margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub ord_froz: MaybeFloat,
+    pub ord_froz: MaybeNumber,
     /// Initial margin requirement in USD
     /// The sum of initial margins of all open positions and pending orders under cross margin mode in USD.
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub imr: MaybeFloat,
+    pub imr: MaybeNumber,
     /// Maintenance margin requirement in USD
     /// The sum of maintenance margins of all open positions under cross margin mode in USD.
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub mmr: MaybeFloat,
+    pub mmr: MaybeNumber,
     /// Potential borrowing IMR of the account in USD
     /// Only applicable to Multi-currency margin and Portfolio margin. It is "" for other margin modes.
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub borrow_froz: MaybeFloat,
+    pub borrow_froz: MaybeNumber,
     /// Margin ratio in USD
     /// The index for measuring the risk of a certain asset in the account.
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub mgn_ratio: MaybeFloat,
+    pub mgn_ratio: MaybeNumber,
     /// Notional value of positions in USD
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub notional_usd: MaybeFloat,
+    pub notional_usd: MaybeNumber,
     /// Detailed asset information in all currencies
     pub details: Vec<TradingBalance>,
 }
@@ -471,10 +810,10 @@ pub struct TradingBalanceDetail {
 pub struct TradingBalance {
     /// Cash Balance
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub cash_bal: MaybeFloat,
+    pub cash_bal: MaybeNumber,
     /// Equity of the currency
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub eq: MaybeFloat,
+    pub eq: MaybeNumber,
     /// Currency
     pub ccy: String,
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
@@ -482,211 +821,1494 @@ pub struct TradingBalance {
     /// Isolated margin equity of the currency
     /// Applicable to Single-currency margin and Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub iso_eq: MaybeFloat,
+    pub iso_eq: MaybeNumber,
     /// Available equity of the currency
     /// The balance that can be used on margin or futures/swap trading.
     /// Applicable to Single-currency margin, Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub avail_eq: MaybeFloat,
+    pub avail_eq: MaybeNumber,
     /// Discount equity of the currency in USD.
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub dis_eq: MaybeFloat,
+    pub dis_eq: MaybeNumber,
     /// Frozen balance
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub fixed_bal: MaybeFloat,
+    pub fixed_bal: MaybeNumber,
     /// Available balance of the currency
     /// The balance that can be withdrawn or transferred or used on spot trading.
     /// Applicable to Simple, Single-currency margin, Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub avail_bal: MaybeFloat,
+    pub avail_bal: MaybeNumber,
     /// Frozen balance of the currency
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub frozen_bal: MaybeFloat,
+    pub frozen_bal: MaybeNumber,
     /// Margin frozen for open orders
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub ord_frozen: MaybeFloat,
+    pub ord_frozen: MaybeNumber,
     /// Liabilities of the currency
     /// It is a positive value, e.g."21625.64". Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub liab: MaybeFloat,
+    pub liab: MaybeNumber,
     /// The sum of the unrealized profit & loss of all margin and derivatives positions of the currency.
     /// Applicable to Single-currency margin, Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub upl: MaybeFloat,
+    pub upl: MaybeNumber,
     /// Liabilities due to Unrealized loss of the currency
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub upl_liab: MaybeFloat,
+    pub upl_liab: MaybeNumber,
     /// Cross liabilities of the currency
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub cross_liab: MaybeFloat,
+    pub cross_liab: MaybeNumber,
     /// Isolated liabilities of the currency
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub iso_liab: MaybeFloat,
+    pub iso_liab: MaybeNumber,
     /// Isolated liabilities of the currency
     /// Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub mgn_ratio: MaybeFloat,
+    pub mgn_ratio: MaybeNumber,
     /// Accrued interest of the currency
     /// It is a positive value, e.g."9.01". Applicable to Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub interest: MaybeFloat,
+    pub interest: MaybeNumber,
     /// Risk indicator of auto liability repayment
     /// Divided into multiple levels from 0 to 5, the larger the number, the more likely the auto repayment will be triggered.
     /// Applicable to Multi-currency margin and Portfolio margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub twap: MaybeFloat,
+    pub twap: MaybeNumber,
     /// Max loan of the currency
     /// Applicable to cross of Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub max_loan: MaybeFloat,
+    pub max_loan: MaybeNumber,
     /// Equity in USD of the currency
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub eq_usd: MaybeFloat,
+    pub eq_usd: MaybeNumber,
     /// Potential borrowing IMR of the currency in USD
     /// Only applicable to Multi-currency margin and Portfolio margin. It is "" for other margin modes.
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub borrow_froz: MaybeFloat,
+    pub borrow_froz: MaybeNumber,
     /// Leverage of the currency
     /// Applicable to Single-currency margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub notional_level: MaybeFloat,
+    pub notional_level: MaybeNumber,
     /// Strategy equity
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub stgy_eq: MaybeFloat,
+    pub stgy_eq: MaybeNumber,
     /// Isolated unrealized profit and loss of the currency
     /// Applicable to Single-currency margin and Multi-currency margin and Portfolio margin
     #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub iso_upl: MaybeFloat,
+    pub iso_upl: MaybeNumber,
 }
 
-// ========== Funding ==========
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct FundingBalance {
-    /// Available balance
-    /// The balance that can be withdrawn or transferred or used for spot trading
+pub struct Position {
+    /// Instrument type
     #[serde(default, with = "str_opt")]
-    pub avail_bal: MaybeFloat,
-    /// Balance
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-180216
     #[serde(default, with = "str_opt")]
-    pub bal: MaybeFloat,
-    /// Frozen balance
+    pub inst_id: MaybeString,
+    /// Margin mode, cross or isolated
     #[serde(default, with = "str_opt")]
-    pub frozen_bal: MaybeFloat,
-    /// Currency
-    pub ccy: String,
+    pub mgn_mode: Option<MarginMode>,
+    /// Position ID
+    #[serde(default, with = "str_opt")]
+    pub pos_id: MaybeString,
+    /// Position side, long or short, net (FUTURES/SWAP/OPTION: positive pos means long position and negative pos means short position. For MARGIN, pos is always positive, posCcy being base currency means long position, posCcy being quote currency means short position.)
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Quantity of positions. In the isolated margin mode, when doing manual transfers, a position with pos of 0 will be generated after the deposit is transferred
+    #[serde(default, with = "str_opt")]
+    pub pos: MaybeFloat,
+    /// Position currency, only applicable to MARGIN positions
+    #[serde(default, with = "str_opt")]
+    pub pos_ccy: MaybeString,
+    /// Position that can be closed
+    /// Only applicable to MARGIN, FUTURES/SWAP in the long-short mode and OPTION.
+    #[serde(default, with = "str_opt")]
+    pub avail_pos: MaybeFloat,
+    /// Average open price
+    #[serde(default, with = "str_opt")]
+    pub avg_px: MaybeFloat,
+    /// Breakeven price
+    #[serde(default, with = "str_opt")]
+    pub be_px: MaybeFloat,
+    /// Unrealized profit and loss calculated by mark price.
+    #[serde(default, with = "str_opt")]
+    pub upl: MaybeFloat,
+    /// Unrealized profit and loss ratio calculated by mark price.
+    #[serde(default, with = "str_opt")]
+    pub upl_ratio: MaybeFloat,
+    /// Unrealized profit and loss calculated by last price.
+    #[serde(default, with = "str_opt")]
+    pub upl_last_px: MaybeFloat,
+    /// Unrealized profit and loss ratio calculated by last price.
+    #[serde(default, with = "str_opt")]
+    pub upl_ratio_last_px: MaybeFloat,
+    /// Leverage, not applicable to OPTION
+    #[serde(default, with = "str_opt")]
+    pub lever: MaybeFloat,
+    /// Estimated liquidation price, not applicable to OPTION
+    #[serde(default, with = "str_opt")]
+    pub liq_px: MaybeFloat,
+    /// Latest Mark price
+    #[serde(default, with = "str_opt")]
+    pub mark_px: MaybeFloat,
+    /// Latest traded price
+    #[serde(default, with = "str_opt")]
+    pub last: MaybeFloat,
+    /// Latest underlying index price
+    #[serde(default, with = "str_opt")]
+    pub idx_px: MaybeFloat,
+    /// Initial margin requirement, only applicable to cross.
+    #[serde(default, with = "str_opt")]
+    pub imr: MaybeFloat,
+    /// Margin, can be added or reduced. Only applicable to isolated.
+    #[serde(default, with = "str_opt")]
+    pub margin: MaybeFloat,
+    /// Margin ratio
+    #[serde(default, with = "str_opt")]
+    pub mgn_ratio: MaybeFloat,
+    /// Maintenance margin requirement
+    #[serde(default, with = "str_opt")]
+    pub mmr: MaybeFloat,
+    /// Liabilities, only applicable to MARGIN.
+    #[serde(default, with = "str_opt")]
+    pub liab: MaybeFloat,
+    /// Liabilities currency, only applicable to MARGIN.
+    #[serde(default, with = "str_opt")]
+    pub liab_ccy: MaybeString,
+    /// Interest. Undeducted interest that has been incurred.
+    #[serde(default, with = "str_opt")]
+    pub interest: MaybeFloat,
+    /// Last trade ID
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
+    /// Option Value, only applicable to OPTION.
+    #[serde(default, with = "str_opt")]
+    pub opt_val: MaybeFloat,
+    /// Notional value of positions in USD
+    #[serde(default, with = "str_opt")]
+    pub notional_usd: MaybeFloat,
+    /// Auto-deleveraging (ADL) indicator
+    /// Divided into 5 levels, from 1 to 5, the smaller the number, the weaker the adl intensity.
+    #[serde(default, with = "str_opt")]
+    pub adl: MaybeU64,
+    /// Currency used for margin
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Accumulated realized profit and loss
+    /// realizedPnl=pnl+fee+fundingFee+liqPenalty
+    #[serde(default, with = "str_opt")]
+    pub realized_pnl: MaybeFloat,
+    /// Accumulated pnl of closing order(s)
+    #[serde(default, with = "str_opt")]
+    pub pnl: MaybeFloat,
+    /// Accumulated fee. Negative number represents the user transaction fee charged by the platform.
+    #[serde(default, with = "str_opt")]
+    pub fee: MaybeFloat,
+    /// Accumulated funding fee
+    #[serde(default, with = "str_opt")]
+    pub funding_fee: MaybeFloat,
+    /// Accumulated liquidation penalty. It is negative when there is a value.
+    #[serde(default, with = "str_opt")]
+    pub liq_penalty: MaybeFloat,
+    /// Close position algo orders attached to the position
+    #[serde(default)]
+    pub close_order_algo: Vec<CloseOrderAlgo>,
+    /// Creation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub c_time: MaybeU64,
+    /// Latest time position was adjusted, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub u_time: MaybeU64,
+    /// Push time of positions information, only pushed by the `positions` channel
+    #[serde(default, with = "str_opt")]
+    pub p_time: MaybeU64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct FundTransferResponse {
-    pub trans_id: String,
-    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub client_id: Option<String>,
-    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub ccy: Option<String>,
-    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub amt: MaybeFloat,
-    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub from: Option<AccountType>,
-    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
-    pub to: Option<AccountType>,
+pub struct CloseOrderAlgo {
+    /// Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_id: MaybeString,
+    /// Stop-loss trigger price.
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px: MaybeFloat,
+    /// Stop-loss trigger price type.
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+    /// Take-profit trigger price.
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px: MaybeFloat,
+    /// Take-profit trigger price type.
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    /// Fraction of position to be closed when the algo order is triggered.
+    #[serde(default, with = "str_opt")]
+    pub close_fraction: MaybeFloat,
 }
 
-// ========== Market ==========
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceAndPosition {
+    /// Push time of both balance and position information, millisecond format of Unix timestamp, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub p_time: MaybeU64,
+    /// Event Type, e.g. snapshot, delivered, exercised, transferred, filled, liquidation
+    #[serde(default, with = "str_opt")]
+    pub event_type: MaybeString,
+    /// Balance data
+    #[serde(default)]
+    pub bal_data: Vec<BalanceData>,
+    /// Position data
+    #[serde(default)]
+    pub pos_data: Vec<PositionData>,
+    /// Details of trade
+    #[serde(default)]
+    pub trades: Vec<TradeRef>,
+}
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Ticker {
-    pub inst_type: Option<String>,
-    pub inst_id: Option<String>,
+pub struct BalanceData {
+    /// Currency
     #[serde(default, with = "str_opt")]
-    pub last: MaybeFloat,
+    pub ccy: MaybeString,
+    /// Cash Balance
     #[serde(default, with = "str_opt")]
-    pub last_sz: MaybeFloat,
+    pub cash_bal: MaybeFloat,
+    /// Update time, Unix timestamp format in milliseconds, e.g. 1597026383085
     #[serde(default, with = "str_opt")]
-    pub ask_px: MaybeFloat,
+    pub u_time: MaybeU64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionData {
+    /// Position ID
     #[serde(default, with = "str_opt")]
-    pub ask_sz: MaybeFloat,
+    pub pos_id: MaybeString,
+    /// Last trade ID
     #[serde(default, with = "str_opt")]
-    pub bid_px: MaybeFloat,
+    pub trade_id: MaybeString,
+    /// Instrument ID, e.g. BTC-USD-180213
     #[serde(default, with = "str_opt")]
-    pub bid_sz: MaybeFloat,
+    pub inst_id: MaybeString,
+    /// Instrument type
     #[serde(default, with = "str_opt")]
-    pub open_24h: MaybeFloat,
+    pub inst_type: Option<InstrumentType>,
+    /// Margin mode, isolated or cross
     #[serde(default, with = "str_opt")]
-    pub high_24h: MaybeFloat,
+    pub mgn_mode: Option<MarginMode>,
+    /// Position side, long or short or net
     #[serde(default, with = "str_opt")]
-    pub low_24h: MaybeFloat,
+    pub pos_side: Option<PositionSide>,
+    /// Quantity of positions
     #[serde(default, with = "str_opt")]
-    pub vol_ccy_24h: MaybeFloat,
+    pub pos: MaybeFloat,
+    /// Currency used for margin
     #[serde(default, with = "str_opt")]
-    pub vol_24h: MaybeFloat,
+    pub ccy: MaybeString,
+    /// Position currency, only applicable to MARGIN positions.
     #[serde(default, with = "str_opt")]
-    pub sod_utc_0: MaybeFloat,
+    pub pos_ccy: MaybeString,
+    /// Average open price
     #[serde(default, with = "str_opt")]
-    pub sod_utc_8: MaybeFloat,
+    pub avg_px: MaybeFloat,
+    /// Update time, Unix timestamp format in milliseconds, e.g. 1597026383085
     #[serde(default, with = "str_opt")]
-    pub ts: MaybeU64,
+    pub u_time: MaybeU64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Candle {
-    /// Opening time of the candlestick, Unix timestamp format in milliseconds, e.g. 1597026383085
+pub struct TradeRef {
+    /// Instrument ID, e.g. BTC-USDT
     #[serde(default, with = "str_opt")]
-    pub ts: MaybeU64,
-    /// Open price
-    #[serde(rename = "o", default, with = "str_opt")]
-    pub open: MaybeFloat,
-    /// highest price
-    #[serde(rename = "h", default, with = "str_opt")]
-    pub high: MaybeFloat,
-    /// Lowest price
-    #[serde(rename = "l", default, with = "str_opt")]
-    pub low: MaybeFloat,
-    /// Close price
-    #[serde(rename = "c", default, with = "str_opt")]
-    pub close: MaybeFloat,
-    /// Trading volume, with a unit of contract.
-    /// If it is a derivatives contract, the value is the number of contracts.
-    /// If it is SPOT/MARGIN, the value is the quantity in base currency.
-    #[serde(rename = "vol", default, with = "str_opt")]
-    pub vol: MaybeFloat,
-    /// Trading volume, with a unit of currency.
-    /// If it is a derivatives contract, the value is the number of base currency.
-    /// If it is SPOT/MARGIN, the value is the quantity in quote currency.
-    #[serde(rename = "volCcy", default, with = "str_opt")]
-    pub vol_ccy: MaybeFloat,
-    /// Trading volume, the value is the quantity in quote currency
-    /// e.g. The unit is USDT for BTC-USDT and BTC-USDT-SWAP;
-    /// The unit is USD for BTC-USD-SWAP
-    #[serde(rename = "volCcyQuote", default, with = "str_opt")]
-    pub vol_ccy_quote: MaybeFloat,
-    /// The state of candlesticks.
-    /// 0 represents that it is uncompleted, 1 represents that it is completed.
-    #[serde(with = "str_opt")]
-    pub confirm: Option<CandleState>,
+    pub inst_id: MaybeString,
+    /// Trade ID
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Platform24Volume {
-    /// Opening time of the candlestick, Unix timestamp format in milliseconds, e.g. 1597026383085
+pub struct PositionHistory {
+    /// Instrument type
     #[serde(default, with = "str_opt")]
-    pub ts: MaybeU64,
-    /// Open price
-    #[serde(rename = "volCny", default, with = "str_opt")]
-    pub vol_cny: MaybeU64,
-    /// highest price
-    #[serde(rename = "volUsd", default, with = "str_opt")]
-    pub vol_usd: MaybeU64,
-}
-
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Margin mode, cross or isolated
+    #[serde(default, with = "str_opt")]
+    pub mgn_mode: Option<MarginMode>,
+    /// The type of latest close position
+    /// 1: Close position partially; 2: Close all; 3: Liquidation; 4: Partial liquidation; 5: ADL
+    #[serde(default, with = "str_opt")]
+    pub r#type: MaybeString,
+    /// Created time of position
+    #[serde(default, with = "str_opt")]
+    pub c_time: MaybeU64,
+    /// Updated time of position
+    #[serde(default, with = "str_opt")]
+    pub u_time: MaybeU64,
+    /// Average price of opening position
+    #[serde(default, with = "str_opt")]
+    pub open_avg_px: MaybeFloat,
+    /// Average price of closing position
+    #[serde(default, with = "str_opt")]
+    pub close_avg_px: MaybeFloat,
+    /// Position ID
+    #[serde(default, with = "str_opt")]
+    pub pos_id: MaybeString,
+    /// Max quantity of position
+    #[serde(default, with = "str_opt")]
+    pub open_max_pos: MaybeFloat,
+    /// Position's cumulative closed volume
+    #[serde(default, with = "str_opt")]
+    pub close_total_pos: MaybeFloat,
+    /// Realized profit and loss, pnl + fee + fundingFee + liqPenalty
+    #[serde(default, with = "str_opt")]
+    pub realized_pnl: MaybeFloat,
+    /// Accumulated fee, negative means the user transaction fee charged by the platform
+    #[serde(default, with = "str_opt")]
+    pub fee: MaybeFloat,
+    /// Accumulated funding fee
+    #[serde(default, with = "str_opt")]
+    pub funding_fee: MaybeFloat,
+    /// Accumulated liquidation penalty, negative when present
+    #[serde(default, with = "str_opt")]
+    pub liq_penalty: MaybeFloat,
+    /// Profit and loss
+    #[serde(default, with = "str_opt")]
+    pub pnl: MaybeFloat,
+    /// Realized P&L ratio
+    #[serde(default, with = "str_opt")]
+    pub pnl_ratio: MaybeFloat,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Leverage
+    #[serde(default, with = "str_opt")]
+    pub lever: MaybeFloat,
+    /// Direction, long or short. Only applicable to MARGIN/FUTURES/SWAP/OPTION
+    #[serde(default, with = "str_opt")]
+    pub direction: MaybeString,
+    /// Trigger mark price, only applicable to liquidation and ADL
+    #[serde(default, with = "str_opt")]
+    pub trigger_px: MaybeFloat,
+    /// Underlying
+    #[serde(default, with = "str_opt")]
+    pub uly: MaybeString,
+    /// Currency used for margin
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    /// Adjusted / Effective equity in USD, applicable to Multi-currency margin and Portfolio margin
+    #[serde(default, with = "str_opt")]
+    pub adj_eq: MaybeFloat,
+    /// Detailed asset information in all currencies
+    #[serde(default)]
+    pub bal_data: Vec<PositionRiskBalance>,
+    /// Detailed position information in all currencies
+    #[serde(default)]
+    pub pos_data: Vec<PositionRiskPosition>,
+    /// Creation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskBalance {
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Equity of currency
+    #[serde(default, with = "str_opt")]
+    pub eq: MaybeFloat,
+    /// Discount equity of currency in USD
+    #[serde(default, with = "str_opt")]
+    pub dis_eq: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskPosition {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Margin mode, cross or isolated
+    #[serde(default, with = "str_opt")]
+    pub mgn_mode: Option<MarginMode>,
+    /// Position ID
+    #[serde(default, with = "str_opt")]
+    pub pos_id: MaybeString,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Quantity of positions contract
+    #[serde(default, with = "str_opt")]
+    pub pos: MaybeFloat,
+    /// Base currency balance, only applicable to MARGIN in Spot and futures mode
+    #[serde(default, with = "str_opt")]
+    pub base_bal: MaybeFloat,
+    /// Quote currency balance, only applicable to MARGIN in Spot and futures mode
+    #[serde(default, with = "str_opt")]
+    pub quote_bal: MaybeFloat,
+    /// Position currency, only applicable to MARGIN positions
+    #[serde(default, with = "str_opt")]
+    pub pos_ccy: MaybeString,
+    /// Currency used for margin
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Notional value of positions in coin
+    #[serde(default, with = "str_opt")]
+    pub notional_ccy: MaybeFloat,
+    /// Notional value of positions in USD
+    #[serde(default, with = "str_opt")]
+    pub notional_usd: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountConfig {
+    /// Account ID of current request
+    #[serde(default, with = "str_opt")]
+    pub uid: MaybeString,
+    /// Main Account ID of current request
+    #[serde(default, with = "str_opt")]
+    pub main_uid: MaybeString,
+    /// Account level
+    /// 1: Simple, 2: Single-currency margin, 3: Multi-currency margin, 4: Portfolio margin
+    #[serde(default, with = "str_opt")]
+    pub acct_lv: MaybeString,
+    /// Position mode, long_short_mode or net_mode, only applicable to FUTURES/SWAP
+    #[serde(default, with = "str_opt")]
+    pub pos_mode: Option<PositionMode>,
+    /// Whether to borrow coins automatically
+    #[serde(default)]
+    pub auto_loan: Option<bool>,
+    /// Current display type of Greeks, PA or BS
+    #[serde(default, with = "str_opt")]
+    pub greeks_type: MaybeString,
+    /// The user level of the current real trading volume on the platform, e.g Lv1
+    #[serde(default, with = "str_opt")]
+    pub level: MaybeString,
+    /// Temporary experience user level of special users, e.g Lv3
+    #[serde(default, with = "str_opt")]
+    pub level_tmp: MaybeString,
+    /// Contract isolated margin trading settings
+    #[serde(default, with = "str_opt")]
+    pub ct_iso_mode: Option<IsolatedMarginMode>,
+    /// Margin isolated margin trading settings
+    #[serde(default, with = "str_opt")]
+    pub mgn_iso_mode: Option<IsolatedMarginMode>,
+    /// Risk offset type, only applicable to Portfolio margin
+    #[serde(default, with = "str_opt")]
+    pub spot_offset_type: MaybeString,
+    /// Role type, 0: General user, 1: Leading trader, 2: Copy trader
+    #[serde(default, with = "str_opt")]
+    pub role_type: MaybeString,
+    /// Whether the optional trading was activated, 0: not activate, 1: activated
+    #[serde(default, with = "str_opt")]
+    pub op_auth: MaybeString,
+    /// Main account KYC level
+    #[serde(default, with = "str_opt")]
+    pub kyc_lv: MaybeString,
+    /// API key note of current request API key
+    #[serde(default, with = "str_opt")]
+    pub label: MaybeString,
+    /// IP addresses that linked with current API key, separate with commas if more than one
+    #[serde(default, with = "str_opt")]
+    pub ip: MaybeString,
+    /// The permission of the current requesting API key, read_only, trade or withdraw
+    #[serde(default, with = "str_opt")]
+    pub perm: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionModeConfig {
+    /// Position mode
+    #[serde(default, with = "str_opt")]
+    pub pos_mode: Option<PositionMode>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leverage {
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Currency, only applicable to cross MARGIN
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Margin mode
+    #[serde(default, with = "str_opt")]
+    pub mgn_mode: Option<MarginMode>,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Leverage
+    #[serde(default, with = "str_opt")]
+    pub lever: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxSize {
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Currency used for margin
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Maximum quantity to buy
+    #[serde(default, with = "str_opt")]
+    pub max_buy: MaybeFloat,
+    /// Maximum quantity to sell
+    #[serde(default, with = "str_opt")]
+    pub max_sell: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxAvailSize {
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Amount available to buy
+    #[serde(default, with = "str_opt")]
+    pub avail_buy: MaybeFloat,
+    /// Amount available to sell
+    #[serde(default, with = "str_opt")]
+    pub avail_sell: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginBalance {
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Amount to be increased or decreased
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+    /// Adjustment type, add or reduce
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<MarginBalanceType>,
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedModeConfig {
+    /// Isolated margin trading settings
+    #[serde(default, with = "str_opt")]
+    pub iso_mode: Option<IsolatedMarginMode>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Fee rate level
+    #[serde(default, with = "str_opt")]
+    pub level: MaybeString,
+    /// Taker fee rate for USDT/USD margined contracts and the currency pairs of SPOT
+    #[serde(default, with = "str_opt")]
+    pub taker: MaybeFloat,
+    /// Maker fee rate for USDT/USD margined contracts and the currency pairs of SPOT
+    #[serde(default, with = "str_opt")]
+    pub maker: MaybeFloat,
+    /// Taker fee rate for USDT-margined contracts
+    #[serde(default, with = "str_opt")]
+    pub taker_u: MaybeFloat,
+    /// Maker fee rate for USDT-margined contracts
+    #[serde(default, with = "str_opt")]
+    pub maker_u: MaybeFloat,
+    /// Taker fee rate for USDC-margined contracts and the USDC currency pairs of SPOT
+    #[serde(default, with = "str_opt")]
+    pub taker_usdc: MaybeFloat,
+    /// Maker fee rate for USDC-margined contracts and the USDC currency pairs of SPOT
+    #[serde(default, with = "str_opt")]
+    pub maker_usdc: MaybeFloat,
+    /// Delivery fee rate
+    #[serde(default, with = "str_opt")]
+    pub delivery: MaybeFloat,
+    /// Fee rate for exercising the option
+    #[serde(default, with = "str_opt")]
+    pub exercise: MaybeFloat,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bill {
+    /// Bill ID
+    #[serde(default, with = "str_opt")]
+    pub bill_id: MaybeString,
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Account balance currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Margin mode, isolated, cross or cash
+    #[serde(default, with = "str_opt")]
+    pub mgn_mode: MaybeString,
+    /// Bill type
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<BillType>,
+    /// Bill subtype
+    #[serde(default, with = "str_opt")]
+    pub sub_type: Option<BillSubType>,
+    /// Balance at the account level
+    #[serde(default, with = "str_opt")]
+    pub bal: MaybeFloat,
+    /// Change in balance amount at the account level
+    #[serde(default, with = "str_opt")]
+    pub bal_chg: MaybeFloat,
+    /// Balance at the position level
+    #[serde(default, with = "str_opt")]
+    pub pos_bal: MaybeFloat,
+    /// Change in balance amount at the position level
+    #[serde(default, with = "str_opt")]
+    pub pos_bal_chg: MaybeFloat,
+    /// Quantity
+    #[serde(default, with = "str_opt")]
+    pub sz: MaybeFloat,
+    /// Price, related to subType
+    #[serde(default, with = "str_opt")]
+    pub px: MaybeFloat,
+    /// Profit and loss
+    #[serde(default, with = "str_opt")]
+    pub pnl: MaybeFloat,
+    /// Fee, negative means the user transaction fee charged by the platform, positive means rebate
+    #[serde(default, with = "str_opt")]
+    pub fee: MaybeFloat,
+    /// Interest
+    #[serde(default, with = "str_opt")]
+    pub interest: MaybeFloat,
+    /// Liquidity taker or maker, only applicable to trade bills
+    #[serde(default, with = "str_opt")]
+    pub exec_type: Option<ExecType>,
+    /// The remitting account, 6: Funding account, 18: Trading account
+    #[serde(default, with = "str_opt")]
+    pub from: MaybeString,
+    /// The beneficiary account, 6: Funding account, 18: Trading account
+    #[serde(default, with = "str_opt")]
+    pub to: MaybeString,
+    /// Notes
+    #[serde(default, with = "str_opt")]
+    pub notes: MaybeString,
+    /// Order ID
+    #[serde(default, with = "str_opt")]
+    pub ord_id: MaybeString,
+    /// Client Order ID as assigned by the client
+    #[serde(default, with = "str_opt")]
+    pub cl_ord_id: MaybeString,
+    /// Order tag
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// Last trade ID
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
+    /// Last filled time, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub fill_time: MaybeU64,
+    /// Creation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestAccrued {
+    /// Loan type, 1: VIP loans, 2: Market loans
+    #[serde(default, with = "str_opt")]
+    pub r#type: MaybeString,
+    /// Loan currency, e.g. BTC
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Instrument ID, only applicable to Margin
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Margin mode, cross or isolated
+    #[serde(default, with = "str_opt")]
+    pub mgn_mode: Option<MarginMode>,
+    /// Interest
+    #[serde(default, with = "str_opt")]
+    pub interest: MaybeFloat,
+    /// Interest rate in hours
+    #[serde(default, with = "str_opt")]
+    pub interest_rate: MaybeFloat,
+    /// Liability
+    #[serde(default, with = "str_opt")]
+    pub liab: MaybeFloat,
+    /// Timestamp for interest accured, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRate {
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Interest rate
+    #[serde(default, with = "str_opt")]
+    pub interest_rate: MaybeFloat,
+}
+
+// ========== Funding ==========
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingBalance {
+    /// Available balance
+    /// The balance that can be withdrawn or transferred or used for spot trading
+    #[serde(default, with = "str_opt")]
+    pub avail_bal: MaybeFloat,
+    /// Balance
+    #[serde(default, with = "str_opt")]
+    pub bal: MaybeFloat,
+    /// Frozen balance
+    #[serde(default, with = "str_opt")]
+    pub frozen_bal: MaybeFloat,
+    /// Currency
+    pub ccy: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundTransferResponse {
+    pub trans_id: String,
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub client_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub ccy: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub amt: MaybeFloat,
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub from: Option<AccountType>,
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub to: Option<AccountType>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundTransferState {
+    /// Transfer ID
+    #[serde(default, with = "str_opt")]
+    pub trans_id: MaybeString,
+    /// Client-supplied ID
+    #[serde(default, with = "str_opt")]
+    pub client_id: MaybeString,
+    /// Currency, e.g. USDT
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Amount to be transferred
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+    /// Transfer type
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<TransferType>,
+    /// The remitting account
+    #[serde(default, with = "str_opt")]
+    pub from: Option<AccountType>,
+    /// The beneficiary account
+    #[serde(default, with = "str_opt")]
+    pub to: Option<AccountType>,
+    /// Name of the sub-account
+    #[serde(default, with = "str_opt")]
+    pub sub_acct: MaybeString,
+    /// Transfer state
+    #[serde(default, with = "str_opt")]
+    pub state: Option<TransferState>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyInfo {
+    /// Currency, e.g. BTC
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Name of currency
+    #[serde(default, with = "str_opt")]
+    pub name: MaybeString,
+    /// The logo link of currency
+    #[serde(default, with = "str_opt")]
+    pub logo_link: MaybeString,
+    /// Chain name, e.g. USDT-ERC20
+    #[serde(default, with = "str_opt")]
+    pub chain: MaybeString,
+    /// The availability to deposit from chain
+    #[serde(default)]
+    pub can_dep: Option<bool>,
+    /// The availability to withdraw to chain
+    #[serde(default)]
+    pub can_wd: Option<bool>,
+    /// The availability to internal transfer
+    #[serde(default)]
+    pub can_internal: Option<bool>,
+    /// The minimum deposit amount of currency in a single transaction
+    #[serde(default, with = "str_opt")]
+    pub min_dep: MaybeFloat,
+    /// The minimum on-chain withdrawal amount of currency in a single transaction
+    #[serde(default, with = "str_opt")]
+    pub min_wd: MaybeFloat,
+    /// The maximum amount of currency on-chain withdrawal in a single transaction
+    #[serde(default, with = "str_opt")]
+    pub max_wd: MaybeFloat,
+    /// The withdrawal precision, indicating the number of digits after the decimal point
+    #[serde(default, with = "str_opt")]
+    pub wd_tick_sz: MaybeU64,
+    /// The withdrawal limit in the past 24 hours (including on-chain withdrawal and internal transfer), unit in USD
+    #[serde(default, with = "str_opt")]
+    pub wd_quota: MaybeFloat,
+    /// The amount of currency withdrawal used in the past 24 hours, unit in USD
+    #[serde(default, with = "str_opt")]
+    pub used_wd_quota: MaybeFloat,
+    /// The minimum withdrawal fee for normal address
+    #[serde(default, with = "str_opt")]
+    pub min_fee: MaybeFloat,
+    /// The maximum withdrawal fee for normal address
+    #[serde(default, with = "str_opt")]
+    pub max_fee: MaybeFloat,
+    /// If current chain is main net
+    #[serde(default)]
+    pub main_net: Option<bool>,
+    /// Whether tag/memo information is required for withdrawal
+    #[serde(default)]
+    pub need_tag: Option<bool>,
+    /// The minimum number of blockchain confirmations to acknowledge fund deposit
+    #[serde(default, with = "str_opt")]
+    pub min_dep_arrival_confirm: MaybeU64,
+    /// The minimum number of blockchain confirmations required for withdrawal of a deposit
+    #[serde(default, with = "str_opt")]
+    pub min_wd_unlock_confirm: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    /// Deposit address
+    #[serde(default, with = "str_opt")]
+    pub addr: MaybeString,
+    /// Deposit tag (This will not be returned if the currency does not require a tag for deposit)
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// Deposit memo (This will not be returned if the currency does not require a memo for deposit)
+    #[serde(default, with = "str_opt")]
+    pub memo: MaybeString,
+    /// Deposit payment ID (This will not be returned if the currency does not require a payment_id for deposit)
+    #[serde(default, with = "str_opt")]
+    pub pmt_id: MaybeString,
+    /// Currency, e.g. BTC
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Chain name, e.g. USDT-ERC20
+    #[serde(default, with = "str_opt")]
+    pub chain: MaybeString,
+    /// The beneficiary account, 6: Funding account, 18: Trading account
+    #[serde(default, with = "str_opt")]
+    pub to: Option<AccountType>,
+    /// Return true if the current deposit address is selected by the website page
+    #[serde(default)]
+    pub selected: Option<bool>,
+    /// Last 6 digits of contract address
+    #[serde(default, with = "str_opt")]
+    pub ct_addr: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Deposit ID
+    #[serde(default, with = "str_opt")]
+    pub dep_id: MaybeString,
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Chain name
+    #[serde(default, with = "str_opt")]
+    pub chain: MaybeString,
+    /// Deposit amount
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+    /// Deposit account, only the internal OKX account is returned
+    #[serde(default, with = "str_opt")]
+    pub from: MaybeString,
+    /// Deposit address
+    #[serde(default, with = "str_opt")]
+    pub to: MaybeString,
+    /// Hash record of the deposit
+    #[serde(default, with = "str_opt")]
+    pub tx_id: MaybeString,
+    /// Internal transfer initiator's withdrawal ID
+    #[serde(default, with = "str_opt")]
+    pub from_wd_id: MaybeString,
+    /// Actual amount of blockchain confirmed in a single deposit
+    #[serde(default, with = "str_opt")]
+    pub actual_dep_blk_confirm: MaybeU64,
+    /// Status of deposit
+    #[serde(default, with = "str_opt")]
+    pub state: Option<DepositState>,
+    /// Time that the deposit record is created, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalResponse {
+    /// Withdrawal ID
+    #[serde(default, with = "str_opt")]
+    pub wd_id: MaybeString,
+    /// Client-supplied ID
+    #[serde(default, with = "str_opt")]
+    pub client_id: MaybeString,
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Chain name, e.g. USDT-ERC20
+    #[serde(default, with = "str_opt")]
+    pub chain: MaybeString,
+    /// Withdrawal amount
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelWithdrawalResponse {
+    /// Withdrawal ID
+    #[serde(default, with = "str_opt")]
+    pub wd_id: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecord {
+    /// Withdrawal ID
+    #[serde(default, with = "str_opt")]
+    pub wd_id: MaybeString,
+    /// Client-supplied ID
+    #[serde(default, with = "str_opt")]
+    pub client_id: MaybeString,
+    /// Currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Chain name, e.g. USDT-ERC20
+    #[serde(default, with = "str_opt")]
+    pub chain: MaybeString,
+    /// Token amount
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+    /// Withdrawal account, it can be email/phone/sub-account name
+    #[serde(default, with = "str_opt")]
+    pub from: MaybeString,
+    /// Receiving address
+    #[serde(default, with = "str_opt")]
+    pub to: MaybeString,
+    /// Some currencies require a tag for withdrawals
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// Some currencies require a payment ID for withdrawals
+    #[serde(default, with = "str_opt")]
+    pub pmt_id: MaybeString,
+    /// Some currencies require this parameter for withdrawals
+    #[serde(default, with = "str_opt")]
+    pub memo: MaybeString,
+    /// Hash record of the withdrawal
+    #[serde(default, with = "str_opt")]
+    pub tx_id: MaybeString,
+    /// Withdrawal fee amount
+    #[serde(default, with = "str_opt")]
+    pub fee: MaybeFloat,
+    /// Withdrawal fee currency, e.g. USDT
+    #[serde(default, with = "str_opt")]
+    pub fee_ccy: MaybeString,
+    /// Whether it is a non-tradable asset or not
+    #[serde(default)]
+    pub non_tradable_asset: Option<bool>,
+    /// Status of withdrawal
+    #[serde(default, with = "str_opt")]
+    pub state: Option<WithdrawalState>,
+    /// Time the withdrawal request was submitted, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetValuation {
+    /// Valuation of total account assets
+    #[serde(default, with = "str_opt")]
+    pub total_bal: MaybeFloat,
+    /// Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Asset valuation details for each account
+    #[serde(default)]
+    pub details: Option<AssetValuationDetails>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetValuationDetails {
+    /// Funding account
+    #[serde(default, with = "str_opt")]
+    pub funding: MaybeFloat,
+    /// Trading account
+    #[serde(default, with = "str_opt")]
+    pub trading: MaybeFloat,
+    /// Classic account (Deprecated)
+    #[serde(default, with = "str_opt")]
+    pub classic: MaybeFloat,
+    /// Earn account
+    #[serde(default, with = "str_opt")]
+    pub earn: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingBill {
+    /// Bill ID
+    #[serde(default, with = "str_opt")]
+    pub bill_id: MaybeString,
+    /// Client-supplied ID for transfer or withdrawal
+    #[serde(default, with = "str_opt")]
+    pub client_id: MaybeString,
+    /// Account balance currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Change in balance at the account level
+    #[serde(default, with = "str_opt")]
+    pub bal_chg: MaybeFloat,
+    /// Balance at the account level
+    #[serde(default, with = "str_opt")]
+    pub bal: MaybeFloat,
+    /// Bill type
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<FundingBillType>,
+    /// Creation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+// ========== Sub-account ==========
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccount {
+    /// Sub-account type, 1: Standard sub-account, 2: Managed trading sub-account, 5: Custody trading sub-account
+    #[serde(default, with = "str_opt")]
+    pub r#type: MaybeString,
+    /// Sub-account status, true: Normal, false: Frozen
+    #[serde(default)]
+    pub enable: Option<bool>,
+    /// Sub-account name
+    #[serde(default, with = "str_opt")]
+    pub sub_acct: MaybeString,
+    /// Sub-account UID
+    #[serde(default, with = "str_opt")]
+    pub uid: MaybeString,
+    /// Sub-account note
+    #[serde(default, with = "str_opt")]
+    pub label: MaybeString,
+    /// Mobile number that linked with the sub-account
+    #[serde(default, with = "str_opt")]
+    pub mobile: MaybeString,
+    /// If the sub-account switches on the Google Authenticator for login authentication
+    #[serde(default)]
+    pub g_auth: Option<bool>,
+    /// Frozen functions, e.g. trading, convert, transfer, withdrawal, deposit, flexible_loan
+    #[serde(default)]
+    pub frozen_func: Vec<String>,
+    /// Whether the sub-account has the right to transfer out
+    #[serde(default)]
+    pub can_trans_out: Option<bool>,
+    /// Sub-account creation time, Unix timestamp in millisecond format
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferResponse {
+    /// Transfer ID
+    #[serde(default, with = "str_opt")]
+    pub trans_id: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBill {
+    /// Bill ID
+    #[serde(default, with = "str_opt")]
+    pub bill_id: MaybeString,
+    /// Transfer currency
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Transfer amount
+    #[serde(default, with = "str_opt")]
+    pub amt: MaybeFloat,
+    /// Bill type
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<SubAccountBillType>,
+    /// Sub-account name
+    #[serde(default, with = "str_opt")]
+    pub sub_acct: MaybeString,
+    /// Bill ID creation time, Unix timestamp in millisecond format
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferOut {
+    /// Name of the sub-account
+    #[serde(default, with = "str_opt")]
+    pub sub_acct: MaybeString,
+    /// Whether the sub-account can transfer out
+    #[serde(default)]
+    pub can_trans_out: Option<bool>,
+}
+
+// ========== Public Data ==========
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistory {
+    /// Instrument type, SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Predicted funding rate
+    #[serde(default, with = "str_opt")]
+    pub funding_rate: MaybeFloat,
+    /// Actual funding rate
+    #[serde(default, with = "str_opt")]
+    pub realized_rate: MaybeFloat,
+    /// Settlement time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub funding_time: MaybeU64,
+    /// Funding rate mechanism, current_period or next_period
+    #[serde(default, with = "str_opt")]
+    pub method: MaybeString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Open interest in number of contracts
+    #[serde(default, with = "str_opt")]
+    pub oi: MaybeFloat,
+    /// Open interest in number of coin
+    #[serde(default, with = "str_opt")]
+    pub oi_ccy: MaybeFloat,
+    /// Open interest in number of USD
+    #[serde(default, with = "str_opt")]
+    pub oi_usd: MaybeFloat,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceLimit {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT-SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Highest buy limit, returns empty when enabled is false
+    #[serde(default, with = "str_opt")]
+    pub buy_lmt: MaybeFloat,
+    /// Lowest sell limit, returns empty when enabled is false
+    #[serde(default, with = "str_opt")]
+    pub sell_lmt: MaybeFloat,
+    /// Whether price limit is effective
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionTier {
+    /// Underlying, applicable to FUTURES/SWAP/OPTION
+    #[serde(default, with = "str_opt")]
+    pub uly: MaybeString,
+    /// Instrument family, applicable to FUTURES/SWAP/OPTION
+    #[serde(default, with = "str_opt")]
+    pub inst_family: MaybeString,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Tiers
+    #[serde(default, with = "str_opt")]
+    pub tier: MaybeU64,
+    /// The minimum borrowing amount or position of this gear is only applicable to margin/options/perpetual/delivery, the minimum position is 0 by default
+    #[serde(default, with = "str_opt")]
+    pub min_sz: MaybeFloat,
+    /// The maximum borrowing amount or number of positions held in this position is only applicable to margin/options/perpetual/delivery
+    #[serde(default, with = "str_opt")]
+    pub max_sz: MaybeFloat,
+    /// Maintenance margin requirement rate
+    #[serde(default, with = "str_opt")]
+    pub mmr: MaybeFloat,
+    /// Initial margin requirement rate
+    #[serde(default, with = "str_opt")]
+    pub imr: MaybeFloat,
+    /// Maximum available leverage
+    #[serde(default, with = "str_opt")]
+    pub max_lever: MaybeFloat,
+    /// Option Margin Coefficient (only applicable to options)
+    #[serde(default, with = "str_opt")]
+    pub opt_mgn_factor: MaybeFloat,
+    /// Quote currency borrowing amount (only applicable to leverage and the case when instId takes effect)
+    #[serde(default, with = "str_opt")]
+    pub quote_max_loan: MaybeFloat,
+    /// Base currency borrowing amount (only applicable to leverage and the case when instId takes effect)
+    #[serde(default, with = "str_opt")]
+    pub base_max_loan: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimatedPrice {
+    /// Instrument type, FUTURES or OPTION
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT-230630
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Estimated delivery/exercise price
+    #[serde(default, with = "str_opt")]
+    pub settle_px: MaybeFloat,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryExerciseHistory {
+    /// Delivery/exercise time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Delivery/exercise details
+    #[serde(default)]
+    pub details: Vec<DeliveryExerciseDetail>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryExerciseDetail {
+    /// Type
+    #[serde(default, with = "str_opt")]
+    pub r#type: Option<DeliveryExerciseHistoryType>,
+    /// Delivery/exercise contract ID, sent as `insId`
+    #[serde(default, with = "str_opt")]
+    pub ins_id: MaybeString,
+    /// Delivery/exercise price
+    #[serde(default, with = "str_opt")]
+    pub px: MaybeFloat,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSummary {
+    /// Instrument type, OPTION
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-200103-5500-C
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Underlying
+    #[serde(default, with = "str_opt")]
+    pub uly: MaybeString,
+    /// Sensitivity of option price to uly price
+    #[serde(default, with = "str_opt")]
+    pub delta: MaybeFloat,
+    /// The delta is sensitivity to uly price
+    #[serde(default, with = "str_opt")]
+    pub gamma: MaybeFloat,
+    /// Sensitivity of option price to implied volatility
+    #[serde(default, with = "str_opt")]
+    pub vega: MaybeFloat,
+    /// Sensitivity of option price to remaining maturity
+    #[serde(default, with = "str_opt")]
+    pub theta: MaybeFloat,
+    /// Sensitivity of option price to uly price in BS mode
+    #[serde(rename = "deltaBS", default, with = "str_opt")]
+    pub delta_bs: MaybeFloat,
+    /// The delta is sensitivity to uly price in BS mode
+    #[serde(rename = "gammaBS", default, with = "str_opt")]
+    pub gamma_bs: MaybeFloat,
+    /// Sensitivity of option price to implied volatility in BS mode
+    #[serde(rename = "vegaBS", default, with = "str_opt")]
+    pub vega_bs: MaybeFloat,
+    /// Sensitivity of option price to remaining maturity in BS mode
+    #[serde(rename = "thetaBS", default, with = "str_opt")]
+    pub theta_bs: MaybeFloat,
+    /// Leverage
+    #[serde(default, with = "str_opt")]
+    pub lever: MaybeFloat,
+    /// Mark volatility
+    #[serde(default, with = "str_opt")]
+    pub mark_vol: MaybeFloat,
+    /// Bid volatility
+    #[serde(default, with = "str_opt")]
+    pub bid_vol: MaybeFloat,
+    /// Ask volatility
+    #[serde(default, with = "str_opt")]
+    pub ask_vol: MaybeFloat,
+    /// Realized volatility (not currently used)
+    #[serde(default, with = "str_opt")]
+    pub real_vol: MaybeFloat,
+    /// Implied volatility of at-the-money options
+    #[serde(default, with = "str_opt")]
+    pub vol_lv: MaybeFloat,
+    /// Forward price
+    #[serde(default, with = "str_opt")]
+    pub fwd_px: MaybeFloat,
+    /// Data update time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+// ========== Market ==========
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub inst_type: Option<String>,
+    pub inst_id: Option<String>,
+    #[serde(default, with = "str_opt")]
+    pub last: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub last_sz: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub ask_px: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub ask_sz: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub bid_px: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub bid_sz: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub open_24h: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub high_24h: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub low_24h: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub vol_ccy_24h: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub vol_24h: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub sod_utc_0: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub sod_utc_8: MaybeNumber,
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candle {
+    /// Opening time of the candlestick, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Open price
+    #[serde(rename = "o", default, with = "str_opt")]
+    pub open: MaybeNumber,
+    /// highest price
+    #[serde(rename = "h", default, with = "str_opt")]
+    pub high: MaybeNumber,
+    /// Lowest price
+    #[serde(rename = "l", default, with = "str_opt")]
+    pub low: MaybeNumber,
+    /// Close price
+    #[serde(rename = "c", default, with = "str_opt")]
+    pub close: MaybeNumber,
+    /// Trading volume, with a unit of contract.
+    /// If it is a derivatives contract, the value is the number of contracts.
+    /// If it is SPOT/MARGIN, the value is the quantity in base currency.
+    #[serde(rename = "vol", default, with = "str_opt")]
+    pub vol: MaybeNumber,
+    /// Trading volume, with a unit of currency.
+    /// If it is a derivatives contract, the value is the number of base currency.
+    /// If it is SPOT/MARGIN, the value is the quantity in quote currency.
+    #[serde(rename = "volCcy", default, with = "str_opt")]
+    pub vol_ccy: MaybeNumber,
+    /// Trading volume, the value is the quantity in quote currency
+    /// e.g. The unit is USDT for BTC-USDT and BTC-USDT-SWAP;
+    /// The unit is USD for BTC-USD-SWAP
+    #[serde(rename = "volCcyQuote", default, with = "str_opt")]
+    pub vol_ccy_quote: MaybeNumber,
+    /// The state of candlesticks.
+    /// 0 represents that it is uncompleted, 1 represents that it is completed.
+    #[serde(with = "str_opt")]
+    pub confirm: Option<CandleState>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Platform24Volume {
+    /// Opening time of the candlestick, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Open price
+    #[serde(rename = "volCny", default, with = "str_opt")]
+    pub vol_cny: MaybeU64,
+    /// highest price
+    #[serde(rename = "volUsd", default, with = "str_opt")]
+    pub vol_usd: MaybeU64,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CandleOHLC {
@@ -710,3 +2332,240 @@ pub struct CandleOHLC {
     #[serde(with = "str_opt")]
     pub confirm: Option<CandleState>,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// Instrument ID, e.g. BTC-USDT
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Trade ID
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
+    /// Trade price
+    #[serde(default, with = "str_opt")]
+    pub px: MaybeFloat,
+    /// Trade quantity
+    /// For spot trading, the unit is base currency
+    /// For FUTURES/SWAP/OPTION, the unit is contract.
+    #[serde(default, with = "str_opt")]
+    pub sz: MaybeFloat,
+    /// Trade side of taker
+    #[serde(default, with = "str_opt")]
+    pub side: Option<Side>,
+    /// The count of trades aggregated, only pushed by the `trades` channel
+    #[serde(default, with = "str_opt")]
+    pub count: MaybeU64,
+    /// Trade time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Mark price
+    #[serde(default, with = "str_opt")]
+    pub mark_px: MaybeFloat,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexTicker {
+    /// Index, e.g. BTC-USD
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Latest index price
+    #[serde(default, with = "str_opt")]
+    pub idx_px: MaybeFloat,
+    /// Highest price in the past 24 hours
+    #[serde(default, with = "str_opt")]
+    pub high_24h: MaybeFloat,
+    /// Lowest price in the past 24 hours
+    #[serde(default, with = "str_opt")]
+    pub low_24h: MaybeFloat,
+    /// Open price in the past 24 hours
+    #[serde(default, with = "str_opt")]
+    pub open_24h: MaybeFloat,
+    /// Open price in the UTC 0
+    #[serde(default, with = "str_opt")]
+    pub sod_utc_0: MaybeFloat,
+    /// Open price in the UTC 8
+    #[serde(default, with = "str_opt")]
+    pub sod_utc_8: MaybeFloat,
+    /// Index price update time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Instrument type, SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-SWAP
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Funding rate mechanism, current_period or next_period
+    #[serde(default, with = "str_opt")]
+    pub method: MaybeString,
+    /// Current funding rate
+    #[serde(default, with = "str_opt")]
+    pub funding_rate: MaybeFloat,
+    /// Forecasted funding rate for the next period
+    #[serde(default, with = "str_opt")]
+    pub next_funding_rate: MaybeFloat,
+    /// Settlement time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub funding_time: MaybeU64,
+    /// Forecasted funding time for the next period, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub next_funding_time: MaybeU64,
+    /// The lower limit of the funding rate
+    #[serde(default, with = "str_opt")]
+    pub min_funding_rate: MaybeFloat,
+    /// The upper limit of the funding rate
+    #[serde(default, with = "str_opt")]
+    pub max_funding_rate: MaybeFloat,
+    /// Settlement state of funding rate, processing or settled
+    #[serde(default, with = "str_opt")]
+    pub sett_state: MaybeString,
+    /// If settState = processing, it is the funding rate that is being used for current settlement cycle.
+    /// If settState = settled, it is the funding rate that is being used for previous settlement cycle
+    #[serde(default, with = "str_opt")]
+    pub sett_funding_rate: MaybeFloat,
+    /// Premium between the mid price of perps market and the index price
+    #[serde(default, with = "str_opt")]
+    pub premium: MaybeFloat,
+    /// Data return time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+}
+
+/// Price level of an order book, sent as `["px", "sz", "0", "ordCount"]`,
+/// or `["px", "sz", "ordCount"]` by `/market/books-full`.
+///
+/// Price and size are kept as sent, the book checksum is computed over the exact strings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Vec<String>")]
+pub struct BookLevel {
+    /// Price
+    pub px: String,
+    /// Quantity at the price, `0` removes the level in an update
+    pub sz: String,
+    /// Number of orders at the price
+    pub ord_count: MaybeU64,
+}
+
+impl BookLevel {
+    pub fn price(&self) -> Option<Decimal> {
+        Decimal::from_str(&self.px).ok()
+    }
+
+    pub fn size(&self) -> Option<Decimal> {
+        Decimal::from_str(&self.sz).ok()
+    }
+}
+
+impl TryFrom<Vec<String>> for BookLevel {
+    type Error = String;
+
+    fn try_from(fields: Vec<String>) -> Result<Self, Self::Error> {
+        let ord_count = match fields.len() {
+            3 => &fields[2],
+            4 => &fields[3],
+            len => return Err(format!("expected 3 or 4 fields in price level, got {len}")),
+        };
+        Ok(Self {
+            ord_count: ord_count.parse().ok(),
+            px: fields[0].clone(),
+            sz: fields[1].clone(),
+        })
+    }
+}
+
+/// Order book returned by `/market/books`, and snapshot or update pushed on the `books`, `books5`,
+/// `bbo-tbt`, `books50-l2-tbt` and `books-l2-tbt` channels
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookUpdate {
+    /// Order book on sell side
+    #[serde(default)]
+    pub asks: Vec<BookLevel>,
+    /// Order book on buy side
+    #[serde(default)]
+    pub bids: Vec<BookLevel>,
+    /// Order book generation time, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Checksum of the first 25 levels of the book after applying this message, only pushed by channels
+    #[serde(default)]
+    pub checksum: Option<i32>,
+    /// Sequence ID of the previous message, `-1` for snapshots, only pushed by channels
+    #[serde(default)]
+    pub prev_seq_id: Option<i64>,
+    /// Sequence ID of this message, only pushed by channels
+    #[serde(default)]
+    pub seq_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionFamilyTrades {
+    /// 24h trading volume, with a unit of contract.
+    #[serde(default, with = "str_opt")]
+    pub vol_24h: MaybeFloat,
+    /// Option type, C: Call P: Put
+    #[serde(default, with = "str_opt")]
+    pub opt_type: Option<OptionType>,
+    /// The list of trade data
+    #[serde(default)]
+    pub trade_info: Vec<Trade>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexComponents {
+    /// Index, e.g. BTC-USD
+    #[serde(default, with = "str_opt")]
+    pub index: MaybeString,
+    /// Latest Index Price
+    #[serde(default, with = "str_opt")]
+    pub last: MaybeFloat,
+    /// Data generation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Components
+    #[serde(default)]
+    pub components: Vec<IndexComponent>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexComponent {
+    /// Name of Exchange
+    #[serde(default, with = "str_opt")]
+    pub exch: MaybeString,
+    /// Name of Exchange Trading Pairs
+    #[serde(default, with = "str_opt")]
+    pub symbol: MaybeString,
+    /// Price of Exchange Trading Pairs
+    #[serde(default, with = "str_opt")]
+    pub sym_px: MaybeFloat,
+    /// Weights
+    #[serde(default, with = "str_opt")]
+    pub wgt: MaybeFloat,
+    /// Price converted to index
+    #[serde(default, with = "str_opt")]
+    pub cnv_px: MaybeFloat,
+}
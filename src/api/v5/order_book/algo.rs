@@ -0,0 +1,714 @@
+use crate::api::rate_limit::instrument_bucket;
+use crate::api::v5::model::{
+    AlgoOrderState, AlgoOrderType, InstrumentType, PositionSide, QuantityType, Side,
+    StopLossTriggerPriceType, TakeProfitTriggerPriceType, TradeMode,
+};
+use crate::api::v5::order_book::trade::BatchSizeError;
+use crate::api::v5::{impl_batch_item, Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
+use crate::serde_util::*;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderDetail {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Margin currency, only applicable to cross MARGIN orders in Single-currency margin.
+    #[serde(default, with = "str_opt")]
+    pub ccy: MaybeString,
+    /// Latest order ID
+    #[serde(default, with = "str_opt")]
+    pub ord_id: MaybeString,
+    /// Order ID list. There will be multiple order IDs when there is TP/SL splitting order.
+    #[serde(default)]
+    pub ord_id_list: Vec<String>,
+    /// Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_id: MaybeString,
+    /// Client Order ID as assigned by the client
+    #[serde(default, with = "str_opt")]
+    pub cl_ord_id: MaybeString,
+    /// Client-supplied Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_cl_ord_id: MaybeString,
+    /// Quantity to buy or sell
+    #[serde(default, with = "str_opt")]
+    pub sz: MaybeFloat,
+    /// Order type
+    #[serde(default, with = "str_opt")]
+    pub ord_type: Option<AlgoOrderType>,
+    /// Order side
+    #[serde(default, with = "str_opt")]
+    pub side: Option<Side>,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Trade mode
+    #[serde(default, with = "str_opt")]
+    pub td_mode: Option<TradeMode>,
+    /// Order quantity unit setting for sz, base_ccy or quote_ccy
+    #[serde(default, with = "str_opt")]
+    pub tgt_ccy: Option<QuantityType>,
+    /// Leverage, from 0.01 to 125. Only applicable to MARGIN/FUTURES/SWAP
+    #[serde(default, with = "str_opt")]
+    pub lever: MaybeFloat,
+    /// State
+    #[serde(default, with = "str_opt")]
+    pub state: Option<AlgoOrderState>,
+    /// Take-profit trigger price.
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px: MaybeFloat,
+    /// Take-profit trigger price type.
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    /// Take-profit order price. `-1` means market price.
+    #[serde(default, with = "str_opt")]
+    pub tp_ord_px: MaybeFloat,
+    /// Stop-loss trigger price.
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px: MaybeFloat,
+    /// Stop-loss trigger price type.
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+    /// Stop-loss order price. `-1` means market price.
+    #[serde(default, with = "str_opt")]
+    pub sl_ord_px: MaybeFloat,
+    /// Trigger price
+    #[serde(default, with = "str_opt")]
+    pub trigger_px: MaybeFloat,
+    /// Trigger price type, last, index or mark
+    #[serde(default, with = "str_opt")]
+    pub trigger_px_type: MaybeString,
+    /// Order price for the trigger order. `-1` means market price.
+    #[serde(default, with = "str_opt")]
+    pub ord_px: MaybeFloat,
+    /// Actual order quantity
+    #[serde(default, with = "str_opt")]
+    pub actual_sz: MaybeFloat,
+    /// Actual order price
+    #[serde(default, with = "str_opt")]
+    pub actual_px: MaybeFloat,
+    /// Actual trigger side, tp: take-profit sl: stop-loss. Only applicable to oco order and conditional order
+    #[serde(default, with = "str_opt")]
+    pub actual_side: MaybeString,
+    /// Trigger time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub trigger_time: MaybeU64,
+    /// Price ratio, only applicable to iceberg order or twap order
+    #[serde(default, with = "str_opt")]
+    pub px_var: MaybeFloat,
+    /// Price variance, only applicable to iceberg order or twap order
+    #[serde(default, with = "str_opt")]
+    pub px_spread: MaybeFloat,
+    /// Average amount, only applicable to iceberg order or twap order
+    #[serde(default, with = "str_opt")]
+    pub sz_limit: MaybeFloat,
+    /// Price limit, only applicable to iceberg order or twap order
+    #[serde(default, with = "str_opt")]
+    pub px_limit: MaybeFloat,
+    /// Time interval, only applicable to twap order
+    #[serde(default, with = "str_opt")]
+    pub time_interval: MaybeString,
+    /// Callback price ratio, only applicable to move_order_stop order
+    #[serde(default, with = "str_opt")]
+    pub callback_ratio: MaybeFloat,
+    /// Callback price variance, only applicable to move_order_stop order
+    #[serde(default, with = "str_opt")]
+    pub callback_spread: MaybeFloat,
+    /// Active price, only applicable to move_order_stop order
+    #[serde(default, with = "str_opt")]
+    pub active_px: MaybeFloat,
+    /// Trigger price of the trailing stop, only applicable to move_order_stop order
+    #[serde(default, with = "str_opt")]
+    pub move_trigger_px: MaybeFloat,
+    /// Whether the order can only reduce the position size
+    #[serde(default, with = "str_opt")]
+    pub reduce_only: Option<bool>,
+    /// Order tag
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// Latest traded price
+    #[serde(default, with = "str_opt")]
+    pub last: MaybeFloat,
+    /// Notional value of the order in USD
+    #[serde(default, with = "str_opt")]
+    pub notional_usd: MaybeFloat,
+    /// Error code when the algo order fails to trigger, `0` if triggered
+    #[serde(default, with = "str_opt")]
+    pub fail_code: MaybeString,
+    /// Creation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub c_time: MaybeU64,
+    /// Order updated time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub u_time: MaybeU64,
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-post-place-algo-order
+/// ## Place algo order
+/// The algo order includes trigger order, oco order, conditional order, iceberg order, twap order and trailing order.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate Limit of lead instruments for Copy Trading: 1 request per 2 seconds \
+/// Rate limit rule: UserID
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/order-algo
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceAlgoOrder {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Trade mode
+    /// Margin mode cross isolated
+    /// Non-Margin mode cash
+    pub td_mode: TradeMode,
+    /// Margin currency
+    /// Only applicable to cross MARGIN orders in Single-currency margin.
+    pub ccy: Option<String>,
+    /// Order side, buy sell
+    pub side: Side,
+    /// Position side
+    /// Required in long/short mode and only be long or short
+    pub pos_side: Option<PositionSide>,
+    /// Quantity to buy or sell
+    /// Either sz or closeFraction is required for conditional orders.
+    pub sz: Option<String>,
+    /// Order tag
+    /// A combination of case-sensitive alphanumerics, all numbers, or all letters of up to 16 characters.
+    pub tag: Option<String>,
+    /// Order quantity unit setting for sz
+    /// Only applicable to SPOT traded with Market buy conditional order
+    pub tgt_ccy: Option<QuantityType>,
+    /// Client-supplied Algo ID
+    /// A combination of case-sensitive alphanumerics, all numbers, or all letters of up to 32 characters.
+    pub algo_cl_ord_id: Option<String>,
+    /// Whether the order can only reduce the position size.
+    /// Valid options: true or false. The default value is false.
+    pub reduce_only: Option<bool>,
+    /// Order type and its parameters
+    #[serde(flatten)]
+    pub params: AlgoOrderParams,
+}
+
+/// Parameters of each algo order type, serialized with the `ordType` of the order
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "ordType", rename_all = "snake_case")]
+pub enum AlgoOrderParams {
+    /// One-way stop order, take-profit and/or stop-loss
+    Conditional(TakeProfitStopLoss),
+    /// One-cancels-the-other order, both take-profit and stop-loss
+    Oco(TakeProfitStopLoss),
+    /// Order placed when the trigger price is reached
+    Trigger(TriggerParams),
+    /// Trailing stop order
+    MoveOrderStop(TrailingStopParams),
+    /// Large order split into smaller orders
+    Iceberg(IcebergParams),
+    /// Large order split into smaller orders placed at regular intervals
+    Twap(TwapParams),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TakeProfitStopLoss {
+    /// Take-profit trigger price
+    /// If you fill in this parameter, you should fill in the take-profit order price as well.
+    pub tp_trigger_px: Option<String>,
+    /// Take-profit trigger price type, the default is last
+    pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    /// Take-profit order price
+    /// If the price is -1, take-profit will be executed at the market price.
+    pub tp_ord_px: Option<String>,
+    /// Stop-loss trigger price
+    /// If you fill in this parameter, you should fill in the stop-loss order price.
+    pub sl_trigger_px: Option<String>,
+    /// Stop-loss trigger price type, the default is last
+    pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+    /// Stop-loss order price
+    /// If the price is -1, stop-loss will be executed at the market price.
+    pub sl_ord_px: Option<String>,
+    /// Whether the TP/SL order placed by the user is associated with the corresponding position of the instrument.
+    /// The default is false. Only applicable to FUTURES/SWAP
+    pub cxl_on_close_pos: Option<bool>,
+    /// Fraction of position to be closed when the algo order is triggered, only `1` is supported.
+    /// Only applicable to FUTURES/SWAP in net mode, instead of sz
+    pub close_fraction: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerParams {
+    /// Trigger price
+    pub trigger_px: String,
+    /// Order Price
+    /// If the price is -1, the order will be executed at the market price.
+    pub order_px: String,
+    /// Trigger price type, last, index or mark. The default is last
+    pub trigger_px_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingStopParams {
+    /// Callback price ratio, e.g. 0.01 represents 1%
+    /// Either callbackRatio or callbackSpread is allowed to be passed.
+    pub callback_ratio: Option<String>,
+    /// Callback price variance
+    pub callback_spread: Option<String>,
+    /// Active price
+    /// The system will only start tracking the market and calculating your trigger price after the activation price is reached.
+    pub active_px: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergParams {
+    /// Price ratio, from 0.0001 to 0.01
+    /// Either pxVar or pxSpread is allowed to be passed.
+    pub px_var: Option<String>,
+    /// Price variance
+    pub px_spread: Option<String>,
+    /// Average amount
+    pub sz_limit: String,
+    /// Price limit
+    pub px_limit: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TwapParams {
+    /// Price ratio, from 0.0001 to 0.01
+    /// Either pxVar or pxSpread is allowed to be passed.
+    pub px_var: Option<String>,
+    /// Price variance
+    pub px_spread: Option<String>,
+    /// Average amount
+    pub sz_limit: String,
+    /// Price limit
+    pub px_limit: String,
+    /// Time interval in seconds, from 5 to 120
+    pub time_interval: String,
+}
+
+/// Result of placing, cancelling or amending an algo order
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderResponse {
+    /// Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_id: MaybeString,
+    /// Client Order ID as assigned by the client
+    #[serde(default, with = "str_opt")]
+    pub cl_ord_id: MaybeString,
+    /// Client-supplied Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_cl_ord_id: MaybeString,
+    /// Client Request ID as assigned by the client for order amendment
+    #[serde(default, with = "str_opt")]
+    pub req_id: MaybeString,
+    /// Order tag
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// The code of the event execution result, 0 means success.
+    #[serde(default, with = "str_opt")]
+    pub s_code: MaybeU64,
+    /// Rejection message if the request is unsuccessful.
+    #[serde(default, with = "str_opt")]
+    pub s_msg: MaybeString,
+}
+
+impl_batch_item!(AlgoOrderResponse);
+
+impl Request for PlaceAlgoOrder {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/order-algo";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<AlgoOrderResponse>;
+
+    /// A resent order with the same `algoClOrdId` is rejected as a duplicate instead of being placed twice;
+    /// OKX ignores an empty `algoClOrdId`
+    fn is_idempotent(&self) -> bool {
+        self.algo_cl_ord_id
+            .as_deref()
+            .is_some_and(|id| !id.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAlgoOrder {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Algo ID
+    pub algo_id: String,
+}
+
+/// Maximum number of algo orders per cancel request
+pub const MAX_CANCEL_ALGO_ORDERS: usize = 10;
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-post-cancel-algo-order
+/// ## Cancel algo order
+/// Cancel unfilled algo orders. A maximum of 10 orders can be canceled per request.
+/// Request parameters should be passed in the form of an array.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: UserID
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/cancel-algos
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct CancelAlgoOrders(Vec<CancelAlgoOrder>);
+
+impl CancelAlgoOrders {
+    pub fn orders(&self) -> &[CancelAlgoOrder] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<CancelAlgoOrder> {
+        self.0
+    }
+}
+
+impl TryFrom<Vec<CancelAlgoOrder>> for CancelAlgoOrders {
+    type Error = BatchSizeError;
+
+    fn try_from(orders: Vec<CancelAlgoOrder>) -> Result<Self, Self::Error> {
+        if orders.is_empty() || orders.len() > MAX_CANCEL_ALGO_ORDERS {
+            return Err(BatchSizeError {
+                len: orders.len(),
+                max: MAX_CANCEL_ALGO_ORDERS,
+            });
+        }
+        Ok(Self(orders))
+    }
+}
+
+impl Request for CancelAlgoOrders {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/cancel-algos";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<AlgoOrderResponse>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-post-amend-algo-order
+/// ## Amend algo order
+/// Amend unfilled algo orders (Support Stop order and Trigger order only, not including Move_order_stop order, Iceberg order, TWAP order, Trailing Stop order).
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: UserID + Instrument ID
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/amend-algos
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendAlgoOrder {
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: String,
+    /// Algo ID
+    /// Either algoId or algoClOrdId is required. If both are passed, algoId will be used.
+    pub algo_id: Option<String>,
+    /// Client-supplied Algo ID
+    pub algo_cl_ord_id: Option<String>,
+    /// Whether the order needs to be automatically canceled when the order amendment fails
+    /// Valid options: false or true, the default is false.
+    pub cxl_on_fail: Option<bool>,
+    /// Client Request ID as assigned by the client for order amendment
+    pub req_id: Option<String>,
+    /// New quantity after amendment and it has to be larger than 0.
+    pub new_sz: Option<String>,
+    /// Take-profit trigger price.
+    /// Either the take-profit trigger price or order price is 0, it means that the take-profit is deleted.
+    pub new_tp_trigger_px: Option<String>,
+    /// Take-profit order price
+    /// If the price is -1, take-profit will be executed at the market price.
+    pub new_tp_ord_px: Option<String>,
+    /// Stop-loss trigger price.
+    /// Either the stop-loss trigger price or order price is 0, it means that the stop-loss is deleted.
+    pub new_sl_trigger_px: Option<String>,
+    /// Stop-loss order price
+    /// If the price is -1, stop-loss will be executed at the market price.
+    pub new_sl_ord_px: Option<String>,
+    /// Take-profit trigger price type
+    pub new_tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    /// Stop-loss trigger price type
+    pub new_sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+}
+
+impl Request for AmendAlgoOrder {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/amend-algos";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+
+    type Response = Vec<AlgoOrderResponse>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(instrument_bucket(&self.inst_id))
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-get-algo-order-details
+/// ## Get algo order details
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/order-algo
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAlgoOrderDetails {
+    /// Algo ID
+    /// Either algoId or algoClOrdId is required. If both are passed, algoId will be used.
+    pub algo_id: Option<String>,
+    /// Client-supplied Algo ID
+    pub algo_cl_ord_id: Option<String>,
+}
+
+impl Request for GetAlgoOrderDetails {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/order-algo";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<AlgoOrderDetail>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-get-algo-order-list
+/// ## Get algo order list
+/// Retrieve a list of untriggered Algo orders under the current account.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/orders-algo-pending
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAlgoOrderList {
+    /// Order type
+    pub ord_type: AlgoOrderType,
+    /// Algo ID
+    pub algo_id: Option<String>,
+    /// Client-supplied Algo ID
+    pub algo_cl_ord_id: Option<String>,
+    /// Instrument type
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: Option<String>,
+    /// Pagination of data to return records earlier than the requested `algoId`.
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested `algoId`.
+    pub before: Option<String>,
+    /// Number of results per request. The maximum is 100. The default is 100
+    pub limit: Option<usize>,
+}
+
+impl Request for GetAlgoOrderList {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/orders-algo-pending";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<AlgoOrderDetail>;
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-algo-trading-get-algo-order-history
+/// ## Get algo order history
+/// Retrieve a list of all algo orders under the current account in the last 3 months.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate limit rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/orders-algo-history
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAlgoOrderHistory {
+    /// Order type
+    pub ord_type: AlgoOrderType,
+    /// State, either state or algoId is required
+    pub state: Option<AlgoOrderState>,
+    /// Algo ID, either state or algoId is required
+    pub algo_id: Option<String>,
+    /// Instrument type
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT
+    pub inst_id: Option<String>,
+    /// Pagination of data to return records earlier than the requested `algoId`
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested `algoId`
+    pub before: Option<String>,
+    /// Number of results per request. The maximum is 100. The default is 100
+    pub limit: Option<usize>,
+}
+
+impl Request for GetAlgoOrderHistory {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/orders-algo-history";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<AlgoOrderDetail>;
+}
+
+impl Paginated for GetAlgoOrderHistory {
+    type Item = AlgoOrderDetail;
+    type Cursor = String;
+
+    fn cursor(item: &AlgoOrderDetail) -> Option<String> {
+        item.algo_id.clone()
+    }
+
+    fn timestamp(item: &AlgoOrderDetail) -> Option<u64> {
+        item.c_time
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_place_algo_order() {
+        let order = PlaceAlgoOrder {
+            inst_id: "BTC-USDT-SWAP".to_owned(),
+            td_mode: TradeMode::Cross,
+            ccy: None,
+            side: Side::Sell,
+            pos_side: None,
+            sz: Some("2".to_owned()),
+            tag: None,
+            tgt_ccy: None,
+            algo_cl_ord_id: Some("stop1".to_owned()),
+            reduce_only: Some(true),
+            params: AlgoOrderParams::Conditional(TakeProfitStopLoss {
+                sl_trigger_px: Some("25000".to_owned()),
+                sl_ord_px: Some("-1".to_owned()),
+                sl_trigger_px_type: Some(StopLossTriggerPriceType::Mark),
+                ..Default::default()
+            }),
+        };
+        assert_eq!(
+            serde_json::to_string(&order).unwrap(),
+            r#"{"instId":"BTC-USDT-SWAP","tdMode":"cross","side":"sell","sz":"2","algoClOrdId":"stop1","reduceOnly":true,"ordType":"conditional","slTriggerPx":"25000","slTriggerPxType":"mark","slOrdPx":"-1"}"#
+        );
+
+        let order = PlaceAlgoOrder {
+            params: AlgoOrderParams::MoveOrderStop(TrailingStopParams {
+                callback_ratio: Some("0.01".to_owned()),
+                ..Default::default()
+            }),
+            ..order
+        };
+        assert!(serde_json::to_string(&order)
+            .unwrap()
+            .ends_with(r#""ordType":"move_order_stop","callbackRatio":"0.01"}"#));
+        assert!(order.is_idempotent());
+
+        let order = PlaceAlgoOrder {
+            algo_cl_ord_id: Some(String::new()),
+            ..order
+        };
+        assert!(!order.is_idempotent());
+    }
+
+    #[test]
+    fn test_cancel_algo_orders() {
+        let cancel = |i: usize| CancelAlgoOrder {
+            inst_id: "BTC-USD-240628-60000-C".to_owned(),
+            algo_id: i.to_string(),
+        };
+        let too_many: Vec<_> = (0..11).map(cancel).collect();
+        assert!(CancelAlgoOrders::try_from(too_many).is_err());
+        assert!(CancelAlgoOrders::try_from(vec![]).is_err());
+
+        let req = CancelAlgoOrders::try_from(vec![cancel(1)]).unwrap();
+        assert_eq!(
+            serde_json::to_string(&req).unwrap(),
+            r#"[{"instId":"BTC-USD-240628-60000-C","algoId":"1"}]"#
+        );
+
+        let amend = AmendAlgoOrder {
+            inst_id: "BTC-USD-240628-60000-C".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(amend.rate_limit_key().as_deref(), Some("BTC-USD"));
+    }
+
+    #[test]
+    fn test_serialize_algo_order_list() {
+        let req = GetAlgoOrderList {
+            ord_type: AlgoOrderType::Conditional,
+            algo_id: None,
+            algo_cl_ord_id: None,
+            inst_type: Some(InstrumentType::Swap),
+            inst_id: None,
+            after: None,
+            before: None,
+            limit: Some(10),
+        };
+        assert_eq!(
+            serde_qs::to_string(&req).unwrap(),
+            "ordType=conditional&instType=SWAP&limit=10"
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::api::v5::model::{BookLevel, BookUpdate};
+
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Number of levels per side covered by the checksum
+const CHECKSUM_DEPTH: usize = 25;
+
+/// The local book no longer matches the exchange.
+/// It is cleared and has to be rebuilt from a new snapshot, by resubscribing to the channel.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BookError {
+    #[error("update received before snapshot")]
+    NoSnapshot,
+
+    #[error("sequence gap: expected prevSeqId {expected}, got {prev_seq_id}")]
+    SequenceGap { expected: i64, prev_seq_id: i64 },
+
+    #[error("checksum mismatch: expected {expected}, computed {computed}")]
+    ChecksumMismatch { expected: i32, computed: i32 },
+
+    #[error("invalid price level {0:?}")]
+    InvalidLevel(BookLevel),
+}
+
+/// Local L2 order book maintained from book channel pushes.
+///
+/// ```no_run
+/// # use okx_rs::api::v5::order_book::book::OrderBook;
+/// # use okx_rs::api::v5::ws::{ChannelData, WsMessage};
+/// # fn on_message(book: &mut OrderBook, message: WsMessage) {
+/// if let WsMessage::Push { action, data: ChannelData::Books(updates), .. } = message {
+///     for update in &updates {
+///         if let Err(err) = book.apply(action.as_deref(), update) {
+///             // resubscribe to the channel to receive a new snapshot
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderBook {
+    asks: BTreeMap<Decimal, BookLevel>,
+    bids: BTreeMap<Decimal, BookLevel>,
+    seq_id: Option<i64>,
+    ts: Option<u64>,
+    synced: bool,
+}
+
+impl OrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies a push, `action` is `snapshot`, `update`, or `None` for channels pushing full books
+    /// (`books5`, `bbo-tbt`).
+    ///
+    /// On error the book is cleared, see [`BookError`].
+    pub fn apply(&mut self, action: Option<&str>, update: &BookUpdate) -> Result<(), BookError> {
+        let result = match action {
+            Some("update") => self.update(update),
+            _ => self.snapshot(update),
+        };
+        if result.is_err() {
+            self.reset();
+        }
+        result
+    }
+
+    fn snapshot(&mut self, update: &BookUpdate) -> Result<(), BookError> {
+        self.asks.clear();
+        self.bids.clear();
+        self.merge(update)?;
+        self.synced = true;
+        self.verify(update)
+    }
+
+    fn update(&mut self, update: &BookUpdate) -> Result<(), BookError> {
+        if !self.synced {
+            return Err(BookError::NoSnapshot);
+        }
+        if let (Some(expected), Some(prev_seq_id)) = (self.seq_id, update.prev_seq_id) {
+            if prev_seq_id != expected {
+                return Err(BookError::SequenceGap {
+                    expected,
+                    prev_seq_id,
+                });
+            }
+        }
+        self.merge(update)?;
+        self.verify(update)
+    }
+
+    fn merge(&mut self, update: &BookUpdate) -> Result<(), BookError> {
+        for (side, levels) in [
+            (&mut self.asks, &update.asks),
+            (&mut self.bids, &update.bids),
+        ] {
+            for level in levels {
+                let (Some(px), Some(sz)) = (level.price(), level.size()) else {
+                    return Err(BookError::InvalidLevel(level.clone()));
+                };
+                if sz.is_zero() {
+                    side.remove(&px);
+                } else {
+                    side.insert(px, level.clone());
+                }
+            }
+        }
+        if update.seq_id.is_some() {
+            self.seq_id = update.seq_id;
+        }
+        if update.ts.is_some() {
+            self.ts = update.ts;
+        }
+        Ok(())
+    }
+
+    fn verify(&self, update: &BookUpdate) -> Result<(), BookError> {
+        match update.checksum {
+            Some(expected) => {
+                let computed = self.checksum();
+                if computed == expected {
+                    Ok(())
+                } else {
+                    Err(BookError::ChecksumMismatch { expected, computed })
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Clears the book until the next snapshot
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether a snapshot was applied and every update since then was consistent
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Sequence ID of the last applied message
+    pub fn seq_id(&self) -> Option<i64> {
+        self.seq_id
+    }
+
+    /// Generation time of the last applied message
+    pub fn ts(&self) -> Option<u64> {
+        self.ts
+    }
+
+    /// Asks from the best (lowest) price
+    pub fn asks(&self) -> impl Iterator<Item = &BookLevel> {
+        self.asks.values()
+    }
+
+    /// Bids from the best (highest) price
+    pub fn bids(&self) -> impl Iterator<Item = &BookLevel> {
+        self.bids.values().rev()
+    }
+
+    pub fn best_ask(&self) -> Option<&BookLevel> {
+        self.asks().next()
+    }
+
+    pub fn best_bid(&self) -> Option<&BookLevel> {
+        self.bids().next()
+    }
+
+    /// https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel
+    /// CRC32 of the first 25 levels, as `bid:size:ask:size` pairs interleaved from the top of the book
+    pub fn checksum(&self) -> i32 {
+        let mut bids = self.bids().take(CHECKSUM_DEPTH);
+        let mut asks = self.asks().take(CHECKSUM_DEPTH);
+        let mut fields = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        loop {
+            let (bid, ask) = (bids.next(), asks.next());
+            if bid.is_none() && ask.is_none() {
+                break;
+            }
+            for level in [bid, ask].into_iter().flatten() {
+                fields.push(level.px.as_str());
+                fields.push(level.sz.as_str());
+            }
+        }
+        crc32fast::hash(fields.join(":").as_bytes()) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &str = r#"{"asks":[["3366.8","9","0","3"],["3368","8","0","4"]],"bids":[["3366.1","7","0","3"],["3366","6","0","4"]],"ts":"1597026383085","checksum":-1881014294,"prevSeqId":-1,"seqId":123456}"#;
+
+    fn update(text: &str) -> BookUpdate {
+        serde_json::from_str(text).unwrap()
+    }
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new();
+        book.apply(Some("snapshot"), &update(SNAPSHOT)).unwrap();
+        book
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let book = book();
+        assert!(book.is_synced());
+        assert_eq!(book.seq_id(), Some(123456));
+        assert_eq!(book.best_ask().unwrap().px, "3366.8");
+        assert_eq!(book.best_bid().unwrap().px, "3366.1");
+        assert_eq!(book.best_bid().unwrap().ord_count, Some(3));
+        assert_eq!(book.checksum(), -1881014294);
+    }
+
+    #[test]
+    fn test_update() {
+        let mut book = book();
+        let text = r#"{"asks":[["3366.8","5","0","1"],["3367","3","0","1"]],"bids":[],"ts":"1597026383086","checksum":-1382412343,"prevSeqId":123456,"seqId":123457}"#;
+        book.apply(Some("update"), &update(text)).unwrap();
+        let asks: Vec<_> = book.asks().map(|level| level.px.as_str()).collect();
+        assert_eq!(asks, ["3366.8", "3367", "3368"]);
+        assert_eq!(book.best_ask().unwrap().sz, "5");
+        assert_eq!(book.seq_id(), Some(123457));
+    }
+
+    #[test]
+    fn test_resync() {
+        let mut book = book();
+        let text = r#"{"asks":[],"bids":[["3366","0","0","0"]],"checksum":-1881014294,"prevSeqId":123456,"seqId":123457}"#;
+        assert!(matches!(
+            book.apply(Some("update"), &update(text)),
+            Err(BookError::ChecksumMismatch { .. })
+        ));
+        assert!(!book.is_synced());
+
+        let mut book = self::book();
+        let text =
+            r#"{"asks":[],"bids":[],"checksum":-1881014294,"prevSeqId":123455,"seqId":123457}"#;
+        assert_eq!(
+            book.apply(Some("update"), &update(text)),
+            Err(BookError::SequenceGap {
+                expected: 123456,
+                prev_seq_id: 123455
+            })
+        );
+        assert!(book.best_ask().is_none());
+
+        let text = r#"{"asks":[],"bids":[],"checksum":1,"prevSeqId":123457,"seqId":123458}"#;
+        assert_eq!(
+            book.apply(Some("update"), &update(text)),
+            Err(BookError::NoSnapshot)
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod algo;
+pub mod book;
 pub mod trade;
@@ -1,13 +1,20 @@
+use crate::api::rate_limit::instrument_bucket;
+use crate::api::v5::inst_id::InstIdOrStr;
 use crate::api::v5::model::{
     Category, ExecType, InstrumentType, OrderState, OrderType, PositionSide, QuantityType,
     SelfTradePreventionMode, Side, StopLossTriggerPriceType, TakeProfitTriggerPriceType, TradeMode,
 };
-use crate::api::v5::Request;
+use crate::api::v5::ws::WsRequest;
+use crate::api::v5::{impl_batch_item, Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
 use crate::serde_util::*;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
+use std::convert::TryFrom;
+use std::time::Duration;
+use thiserror::Error;
 
 /// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-place-order
 /// ## Place Order
@@ -24,7 +31,7 @@ use serde_with::{serde_as, skip_serializing_none};
 #[serde(rename_all = "camelCase")]
 pub struct PlaceOrder {
     /// Instrument ID, e.g. BTC-USD-190927-5000-C
-    pub inst_id: String,
+    pub inst_id: InstIdOrStr,
     /// Trade mode
     /// Margin mode cross isolated
     /// Non-Margin mode cash
@@ -62,11 +69,15 @@ pub struct PlaceOrder {
     /// mmp_and_post_only：Market Maker Protection and Post-only order(only applicable to Option in Portfolio Margin mode)V
     pub ord_type: OrderType,
     /// Quantity to buy or sell
-    pub sz: String,
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub sz: OrderNumber,
     /// Order price. Only applicable to limit,post_only,fok,ioc,mmp,mmp_and_post_only order.
     /// When placing an option order, one of px/pxUsd/pxVol must be filled in, and only one can be filled in
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub px: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::serde_util::serialize_as_str_opt"
+    )]
+    pub px: Option<OrderNumber>,
     /// Whether orders can only reduce in position size.
     /// Valid options: true or false. The default value is false.
     /// Only applicable to MARGIN orders, and FUTURES/SWAP orders in net mode
@@ -163,12 +174,39 @@ pub struct PlaceOrderResponse {
     pub ts: MaybeU64,
 }
 
+impl_batch_item!(PlaceOrderResponse);
+
 impl Request for PlaceOrder {
     const METHOD: Method = Method::POST;
     const PATH: &'static str = "/trade/order";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
 
     type Response = Vec<PlaceOrderResponse>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(instrument_bucket(&self.inst_id))
+    }
+
+    /// A resent order with the same `clOrdId` is rejected as a duplicate instead of being placed twice;
+    /// OKX ignores an empty `clOrdId`
+    fn is_idempotent(&self) -> bool {
+        self.cl_ord_id.as_deref().is_some_and(|id| !id.is_empty())
+    }
+}
+
+impl WsRequest for PlaceOrder {
+    const OP: &'static str = "order";
+
+    type Arg = Self;
+
+    fn args(&self) -> &[Self] {
+        std::slice::from_ref(self)
+    }
 }
 
 /// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-cancel-order
@@ -213,12 +251,33 @@ pub struct CancelOrderResponse {
     pub s_msg: MaybeString,
 }
 
+impl_batch_item!(CancelOrderResponse);
+
 impl Request for CancelOrder {
     const METHOD: Method = Method::POST;
     const PATH: &'static str = "/trade/cancel-order";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
 
     type Response = Vec<CancelOrderResponse>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(instrument_bucket(&self.inst_id))
+    }
+}
+
+impl WsRequest for CancelOrder {
+    const OP: &'static str = "cancel-order";
+
+    type Arg = Self;
+
+    fn args(&self) -> &[Self] {
+        std::slice::from_ref(self)
+    }
 }
 
 /// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-cancel-order
@@ -239,8 +298,323 @@ impl Request for CancelMultipleOrders {
     const METHOD: Method = Method::POST;
     const PATH: &'static str = "/trade/cancel-batch-orders";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        300,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
 
     type Response = Vec<CancelOrderResponse>;
+
+    fn rate_limit_cost(&self) -> u32 {
+        self.len() as u32
+    }
+}
+
+impl WsRequest for CancelMultipleOrders {
+    const OP: &'static str = "batch-cancel-orders";
+
+    type Arg = CancelOrder;
+
+    fn args(&self) -> &[CancelOrder] {
+        self
+    }
+}
+
+/// Maximum number of orders per batch request
+pub const MAX_BATCH_ORDERS: usize = 20;
+
+#[derive(Debug, Error)]
+#[error("a batch holds 1 to {max} orders, got {len}")]
+pub struct BatchSizeError {
+    pub len: usize,
+    pub max: usize,
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-place-multiple-orders
+/// ## Place Multiple Orders
+/// Place orders in batches. Maximum 20 orders can be placed per request.
+/// Request parameters should be passed in the form of an array.
+/// Orders are placed independently, check `s_code` of every order in the response
+/// or send the request with [`Rest::request_batch`](crate::api::Rest::request_batch).
+///
+/// Rate Limit: 300 orders per 2 seconds \
+/// Rate Limit of lead instruments for Copy Trading: 4 requests per seconds \
+/// Rate limit rule (except Options): UserID + InstrumentID \
+/// Rate limit rule (options Options): UserID + InstrumentFamily \
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/batch-orders
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct PlaceMultipleOrders(Vec<PlaceOrder>);
+
+impl PlaceMultipleOrders {
+    pub fn orders(&self) -> &[PlaceOrder] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<PlaceOrder> {
+        self.0
+    }
+}
+
+impl TryFrom<Vec<PlaceOrder>> for PlaceMultipleOrders {
+    type Error = BatchSizeError;
+
+    fn try_from(orders: Vec<PlaceOrder>) -> Result<Self, Self::Error> {
+        if orders.is_empty() || orders.len() > MAX_BATCH_ORDERS {
+            return Err(BatchSizeError {
+                len: orders.len(),
+                max: MAX_BATCH_ORDERS,
+            });
+        }
+        Ok(Self(orders))
+    }
+}
+
+impl Request for PlaceMultipleOrders {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/batch-orders";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        300,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+
+    type Response = Vec<PlaceOrderResponse>;
+
+    /// Orders with a non-empty `clOrdId` are rejected as duplicates when resent
+    fn is_idempotent(&self) -> bool {
+        self.0.iter().all(Request::is_idempotent)
+    }
+
+    fn rate_limit_cost(&self) -> u32 {
+        self.0.len() as u32
+    }
+}
+
+impl WsRequest for PlaceMultipleOrders {
+    const OP: &'static str = "batch-orders";
+
+    type Arg = PlaceOrder;
+
+    fn args(&self) -> &[PlaceOrder] {
+        &self.0
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-amend-order
+/// ## Amend Order
+/// Amend an incomplete order.
+///
+/// Rate Limit: 60 requests per 2 seconds \
+/// Rate Limit of lead instruments for Copy Trading: 4 requests per seconds \
+/// Rate limit rule (except Options): UserID + InstrumentID \
+/// Rate limit rule (options Options): UserID + InstrumentFamily \
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/amend-order
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrder {
+    /// Instrument ID, e.g. `BTC-USDT`
+    pub inst_id: String,
+    /// Whether the order needs to be automatically canceled when the order amendment fails
+    /// Valid options: false or true, the default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cxl_on_fail: Option<bool>,
+    /// Order ID
+    /// Either ordId or clOrdId is required. If both are passed, ordId will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ord_id: Option<String>,
+    /// Client Order ID as assigned by the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
+    /// Client Request ID as assigned by the client for order amendment
+    /// A combination of case-sensitive alphanumerics, all numbers, or all letters of up to 32 characters.
+    /// The response will include the corresponding reqId to help you identify the request if you provide it in the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+    /// New quantity after amendment and it has to be larger than 0.
+    /// Either newSz or newPx is required.
+    /// When amending a partially-filled order, the newSz should include the amount that has been filled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sz: Option<String>,
+    /// New price after amendment.
+    /// When modifying options orders, users can only fill in one of the following: newPx, newPxUsd, or newPxVol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_px: Option<String>,
+    /// Modify options orders using USD prices
+    /// Only applicable to options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_px_usd: Option<String>,
+    /// Modify options orders based on implied volatility, where 1 represents 100%
+    /// Only applicable to options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_px_vol: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderResponse {
+    /// Order ID
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub ord_id: MaybeString,
+    /// Client Order ID as assigned by the client
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub cl_ord_id: MaybeString,
+    /// Timestamp when the order request processing is finished by our system,
+    /// Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub ts: MaybeU64,
+    /// Client Request ID as assigned by the client for order amendment.
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub req_id: MaybeString,
+    /// The code of the event execution result, 0 means success.
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub s_code: MaybeU64,
+    /// Rejection message if the request is unsuccessful.
+    #[serde(default, deserialize_with = "deserialize_from_opt_str")]
+    pub s_msg: MaybeString,
+}
+
+impl_batch_item!(AmendOrderResponse);
+
+impl Request for AmendOrder {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/amend-order";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+
+    type Response = Vec<AmendOrderResponse>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(instrument_bucket(&self.inst_id))
+    }
+}
+
+impl WsRequest for AmendOrder {
+    const OP: &'static str = "amend-order";
+
+    type Arg = Self;
+
+    fn args(&self) -> &[Self] {
+        std::slice::from_ref(self)
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-amend-multiple-orders
+/// ## Amend Multiple Orders
+/// Amend incomplete orders in batches. Maximum 20 orders can be amended per request.
+/// Request parameters should be passed in the form of an array.
+///
+/// Rate Limit: 300 orders per 2 seconds \
+/// Rate Limit of lead instruments for Copy Trading: 4 requests per seconds \
+/// Rate limit rule (except Options): UserID + InstrumentID \
+/// Rate limit rule (options Options): UserID + InstrumentFamily \
+///
+/// ### HTTP Requests
+/// **POST** /api/v5/trade/amend-batch-orders
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct AmendMultipleOrders(Vec<AmendOrder>);
+
+impl AmendMultipleOrders {
+    pub fn orders(&self) -> &[AmendOrder] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<AmendOrder> {
+        self.0
+    }
+}
+
+impl TryFrom<Vec<AmendOrder>> for AmendMultipleOrders {
+    type Error = BatchSizeError;
+
+    fn try_from(orders: Vec<AmendOrder>) -> Result<Self, Self::Error> {
+        if orders.is_empty() || orders.len() > MAX_BATCH_ORDERS {
+            return Err(BatchSizeError {
+                len: orders.len(),
+                max: MAX_BATCH_ORDERS,
+            });
+        }
+        Ok(Self(orders))
+    }
+}
+
+impl Request for AmendMultipleOrders {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/trade/amend-batch-orders";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        300,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+
+    type Response = Vec<AmendOrderResponse>;
+
+    fn rate_limit_cost(&self) -> u32 {
+        self.0.len() as u32
+    }
+}
+
+impl WsRequest for AmendMultipleOrders {
+    const OP: &'static str = "batch-amend-orders";
+
+    type Arg = AmendOrder;
+
+    fn args(&self) -> &[AmendOrder] {
+        &self.0
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-order-details
+/// ## Get order details
+/// Retrieve order details.
+///
+/// Rate Limit: 60 requests per 2 seconds \
+/// Rate limit rule (except Options): UserID + InstrumentID \
+/// Rate limit rule (Options only): UserID + Instrument Family
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/order
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderDetails {
+    /// Instrument ID, e.g. `BTC-USDT`
+    pub inst_id: String,
+    /// Order ID
+    /// Either ordId or clOrdId is required, if both are passed, ordId will be used
+    pub ord_id: Option<String>,
+    /// Client Order ID as assigned by the client
+    /// If the clOrdId is associated with multiple orders, only the latest one will be returned.
+    pub cl_ord_id: Option<String>,
+}
+
+impl Request for GetOrderDetails {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/order";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+
+    type Response = Vec<OrderDetail>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        Some(instrument_bucket(&self.inst_id))
+    }
 }
 
 /// https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-order-list
@@ -290,11 +664,11 @@ pub struct OrderDetail {
     #[serde(default, with = "str_opt")]
     pub tag: MaybeString,
     #[serde(default, with = "str_opt")]
-    pub px: MaybeFloat,
+    pub px: MaybeNumber,
     #[serde(default, with = "str_opt")]
-    pub sz: MaybeFloat,
+    pub sz: MaybeNumber,
     #[serde(default, with = "str_opt")]
-    pub pnl: MaybeFloat,
+    pub pnl: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub ord_type: Option<OrderType>,
     #[serde(default, with = "str_opt")]
@@ -304,37 +678,37 @@ pub struct OrderDetail {
     #[serde(default, with = "str_opt")]
     pub td_mode: Option<TradeMode>,
     #[serde(default, with = "str_opt")]
-    pub acc_fill_sz: MaybeFloat,
+    pub acc_fill_sz: MaybeNumber,
     #[serde(default, with = "str_opt")]
-    pub fill_px: MaybeFloat,
+    pub fill_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub trade_id: MaybeString,
     #[serde(default, with = "str_opt")]
-    pub fill_sz: MaybeFloat,
+    pub fill_sz: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub fill_time: MaybeU64,
     #[serde(default, with = "str_opt")]
-    pub avg_px: MaybeFloat,
+    pub avg_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub state: Option<OrderState>,
     #[serde(default, with = "str_opt")]
-    pub lever: MaybeFloat,
+    pub lever: MaybeNumber,
     #[serde(default, with = "str_opt")]
-    pub tp_trigger_px: MaybeFloat,
+    pub tp_trigger_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
     #[serde(default, with = "str_opt")]
-    pub tp_ord_px: MaybeFloat,
+    pub tp_ord_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
-    pub sl_trigger_px: MaybeFloat,
+    pub sl_trigger_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
     #[serde(default, with = "str_opt")]
-    pub sl_ord_px: MaybeFloat,
+    pub sl_ord_px: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub fee_ccy: MaybeString,
     #[serde(default, with = "str_opt")]
-    pub fee: MaybeFloat,
+    pub fee: MaybeNumber,
     #[serde(default, with = "str_opt")]
     pub rebate_ccy: MaybeString,
     #[serde(default, with = "str_opt")]
@@ -355,6 +729,11 @@ impl Request for GetOrderList {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/trade/orders-pending";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
 
     type Response = Vec<OrderDetail>;
 }
@@ -374,40 +753,40 @@ impl Request for GetOrderList {
 pub struct GetOrderHistory {
     /// Instrument `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
     #[serde(default, with = "str_opt")]
-    inst_type: Option<InstrumentType>,
+    pub inst_type: Option<InstrumentType>,
     /// Underlying Applicable to `FUTURES` / `SWAP` / `OPTION`
     #[serde(default, with = "str_opt")]
-    uly: MaybeString,
+    pub uly: MaybeString,
     /// Instrument family Applicable to `FUTURES` / `SWAP` / `OPTION`
     #[serde(default, with = "str_opt")]
-    inst_family: MaybeString,
+    pub inst_family: MaybeString,
     /// Instrument ID, e.g. `BTC-USDT`
     #[serde(default, with = "str_opt")]
-    inst_id: MaybeString,
+    pub inst_id: MaybeString,
     /// Order Type
     #[serde(default, with = "str_opt")]
-    order_type: Option<OrderType>,
+    pub ord_type: Option<OrderType>,
     /// State
     #[serde(default, with = "str_opt")]
-    state: Option<OrderState>,
+    pub state: Option<OrderState>,
     /// Category
     #[serde(default, with = "str_opt")]
-    category: Option<Category>,
+    pub category: Option<Category>,
     /// Pagination of data to return records earlier than the requested `ordId`
     #[serde(default, with = "str_opt")]
-    after: MaybeString,
+    pub after: MaybeString,
     /// Pagination of data to return records newer than the requested `ordId`
     #[serde(default, with = "str_opt")]
-    before: MaybeString,
+    pub before: MaybeString,
     /// Filter with a begin timestamp `cTime`. Unix timestamp format in milliseconds, e.g. 1597026383085
     #[serde(default, with = "str_opt")]
-    begin: MaybeString,
+    pub begin: MaybeString,
     /// Filter with an end timestamp `cTime`. Unix timestamp format in milliseconds, e.g. 1597026383085
     #[serde(default, with = "str_opt")]
-    end: MaybeString,
+    pub end: MaybeString,
     /// Number of results per request. The maximum is `100`; The default is `100`
     #[serde(default, with = "str_opt")]
-    limit: MaybeString,
+    pub limit: MaybeString,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -421,7 +800,8 @@ pub struct OrderHistory {
     pub algo_id: MaybeString,
     #[serde(default, with = "str_opt")]
     pub attach_algo_cl_ord_id: MaybeString,
-    // TODO: attach_algo_ords
+    #[serde(default)]
+    pub attach_algo_ords: Vec<AttachAlgoOrd>,
     #[serde(default, with = "str_opt")]
     pub avg_px: MaybeFloat,
     #[serde(default, with = "str_opt")]
@@ -451,14 +831,737 @@ pub struct OrderHistory {
     #[serde(default, with = "str_opt")]
     pub inst_type: Option<InstrumentType>,
     #[serde(default, with = "str_opt")]
+    pub is_tp_limit: Option<bool>,
+    #[serde(default, with = "str_opt")]
     pub lever: MaybeFloat,
-    // TODO: linked_algo_ord
+    #[serde(default)]
+    pub linked_algo_ord: Option<LinkedAlgoOrd>,
+    #[serde(default, with = "str_opt")]
+    pub ord_id: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub ord_type: Option<OrderType>,
+    #[serde(default, with = "str_opt")]
+    pub pnl: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    #[serde(default, with = "str_opt")]
+    pub px: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub px_type: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub px_usd: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub px_vol: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub quick_mgn_type: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub rebate: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub rebate_ccy: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub reduce_only: Option<bool>,
+    #[serde(default, with = "str_opt")]
+    pub side: Option<Side>,
+    #[serde(default, with = "str_opt")]
+    pub sl_ord_px: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+    #[serde(default, with = "str_opt")]
+    pub source: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub state: Option<OrderState>,
+    #[serde(default, with = "str_opt")]
+    pub stp_id: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub stp_mode: Option<SelfTradePreventionMode>,
+    #[serde(default, with = "str_opt")]
+    pub sz: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub td_mode: Option<TradeMode>,
+    #[serde(default, with = "str_opt")]
+    pub tgt_ccy: Option<QuantityType>,
+    #[serde(default, with = "str_opt")]
+    pub tp_ord_px: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px: MaybeFloat,
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
+    #[serde(default, with = "str_opt")]
+    pub u_time: MaybeU64,
+}
+
+/// TP/SL algo order attached to an order when placing it
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachAlgoOrd {
+    /// The order ID of attached TP/SL order
+    #[serde(default, with = "str_opt")]
+    pub attach_algo_id: MaybeString,
+    /// Client-supplied Algo ID when placing order attaching TP/SL
+    #[serde(default, with = "str_opt")]
+    pub attach_algo_cl_ord_id: MaybeString,
+    /// TP order kind, condition or limit
+    #[serde(default, with = "str_opt")]
+    pub tp_ord_kind: MaybeString,
+    /// Take-profit trigger price
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px: MaybeFloat,
+    /// Take-profit trigger price type
+    #[serde(default, with = "str_opt")]
+    pub tp_trigger_px_type: Option<TakeProfitTriggerPriceType>,
+    /// Take-profit order price, `-1` means market price
+    #[serde(default, with = "str_opt")]
+    pub tp_ord_px: MaybeFloat,
+    /// Stop-loss trigger price
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px: MaybeFloat,
+    /// Stop-loss trigger price type
+    #[serde(default, with = "str_opt")]
+    pub sl_trigger_px_type: Option<StopLossTriggerPriceType>,
+    /// Stop-loss order price, `-1` means market price
+    #[serde(default, with = "str_opt")]
+    pub sl_ord_px: MaybeFloat,
+    /// Size, only applicable to TP order of split TPs
+    #[serde(default, with = "str_opt")]
+    pub sz: MaybeFloat,
+    /// Whether to enable Cost-price SL, `0` disabled, `1` enabled
+    #[serde(default, with = "str_opt")]
+    pub amend_px_on_trigger_type: MaybeString,
+    /// The error code when failing to place the TP/SL order
+    #[serde(default, with = "str_opt")]
+    pub fail_code: MaybeString,
+    /// The error reason when failing to place the TP/SL order
+    #[serde(default, with = "str_opt")]
+    pub fail_reason: MaybeString,
+}
+
+/// Algo order linked to an order placed by a triggered TP/SL
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedAlgoOrd {
+    /// Algo ID
+    #[serde(default, with = "str_opt")]
+    pub algo_id: MaybeString,
 }
 
 impl Request for GetOrderHistory {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/trade/orders-history";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        40,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<OrderHistory>;
+}
+
+impl Paginated for GetOrderHistory {
+    type Item = OrderHistory;
+    type Cursor = String;
+
+    fn cursor(item: &OrderHistory) -> Option<String> {
+        item.ord_id.clone()
+    }
+
+    fn timestamp(item: &OrderHistory) -> Option<u64> {
+        item.c_time
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit.as_deref().and_then(|limit| limit.parse().ok())
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-order-history-last-3-months
+/// ## Order History (last 3 months)
+/// Get completed orders which are placed in the last 3 months, including those placed 3 months ago but completed in the last 3 months.
+///
+/// Rate Limit: 20 requests per 2 seconds \
+/// Rate Limit Rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/orders-history-archive
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderHistoryArchive {
+    /// Instrument `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub inst_type: InstrumentType,
+    /// Underlying Applicable to `FUTURES` / `SWAP` / `OPTION`
+    #[serde(default, with = "str_opt")]
+    pub uly: MaybeString,
+    /// Instrument family Applicable to `FUTURES` / `SWAP` / `OPTION`
+    #[serde(default, with = "str_opt")]
+    pub inst_family: MaybeString,
+    /// Instrument ID, e.g. `BTC-USDT`
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Order Type
+    #[serde(default, with = "str_opt")]
+    pub ord_type: Option<OrderType>,
+    /// State
+    #[serde(default, with = "str_opt")]
+    pub state: Option<OrderState>,
+    /// Category
+    #[serde(default, with = "str_opt")]
+    pub category: Option<Category>,
+    /// Pagination of data to return records earlier than the requested `ordId`
+    #[serde(default, with = "str_opt")]
+    pub after: MaybeString,
+    /// Pagination of data to return records newer than the requested `ordId`
+    #[serde(default, with = "str_opt")]
+    pub before: MaybeString,
+    /// Filter with a begin timestamp `cTime`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub begin: MaybeString,
+    /// Filter with an end timestamp `cTime`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub end: MaybeString,
+    /// Number of results per request. The maximum is `100`; The default is `100`
+    #[serde(default, with = "str_opt")]
+    pub limit: MaybeString,
+}
+
+impl Request for GetOrderHistoryArchive {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/orders-history-archive";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
 
     type Response = Vec<OrderHistory>;
 }
+
+impl Paginated for GetOrderHistoryArchive {
+    type Item = OrderHistory;
+    type Cursor = String;
+
+    fn cursor(item: &OrderHistory) -> Option<String> {
+        item.ord_id.clone()
+    }
+
+    fn timestamp(item: &OrderHistory) -> Option<u64> {
+        item.c_time
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit.as_deref().and_then(|limit| limit.parse().ok())
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-transaction-details-last-3-days
+/// ## Transaction details (last 3 days)
+/// Retrieve recently-filled transaction details in the last 3 day.
+///
+/// Rate Limit: 60 requests per 2 seconds \
+/// Rate Limit Rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/fills
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFills {
+    /// Instrument type `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Underlying Applicable to `FUTURES` / `SWAP` / `OPTION`
+    pub uly: Option<String>,
+    /// Instrument family Applicable to `FUTURES` / `SWAP` / `OPTION`
+    pub inst_family: Option<String>,
+    /// Instrument ID, e.g. `BTC-USDT`
+    pub inst_id: Option<String>,
+    /// Order ID
+    pub ord_id: Option<String>,
+    /// Transaction type, e.g. `1` buy, `2` sell
+    pub sub_type: Option<String>,
+    /// Pagination of data to return records earlier than the requested `billId`
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested `billId`
+    pub before: Option<String>,
+    /// Filter with a begin timestamp `ts`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    pub begin: Option<u64>,
+    /// Filter with an end timestamp `ts`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    pub end: Option<u64>,
+    /// Number of results per request. The maximum is `100`; The default is `100`
+    pub limit: Option<usize>,
+}
+
+impl Request for GetFills {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/fills";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        60,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<Fill>;
+}
+
+impl Paginated for GetFills {
+    type Item = Fill;
+    type Cursor = String;
+
+    fn cursor(item: &Fill) -> Option<String> {
+        item.bill_id.clone()
+    }
+
+    fn timestamp(item: &Fill) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-transaction-details-last-3-months
+/// ## Transaction details (last 3 months)
+/// This endpoint can retrieve data from the last 3 months.
+///
+/// Rate Limit: 10 requests per 2 seconds \
+/// Rate Limit Rule: UserID
+///
+/// ### HTTP Requests
+/// **GET** /api/v5/trade/fills-history
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFillsHistory {
+    /// Instrument type `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub inst_type: InstrumentType,
+    /// Underlying Applicable to `FUTURES` / `SWAP` / `OPTION`
+    pub uly: Option<String>,
+    /// Instrument family Applicable to `FUTURES` / `SWAP` / `OPTION`
+    pub inst_family: Option<String>,
+    /// Instrument ID, e.g. `BTC-USDT`
+    pub inst_id: Option<String>,
+    /// Order ID
+    pub ord_id: Option<String>,
+    /// Transaction type, e.g. `1` buy, `2` sell
+    pub sub_type: Option<String>,
+    /// Pagination of data to return records earlier than the requested `billId`
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested `billId`
+    pub before: Option<String>,
+    /// Filter with a begin timestamp `ts`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    pub begin: Option<u64>,
+    /// Filter with an end timestamp `ts`. Unix timestamp format in milliseconds, e.g. 1597026383085
+    pub end: Option<u64>,
+    /// Number of results per request. The maximum is `100`; The default is `100`
+    pub limit: Option<usize>,
+}
+
+impl Request for GetFillsHistory {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/trade/fills-history";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+
+    type Response = Vec<Fill>;
+}
+
+impl Paginated for GetFillsHistory {
+    type Item = Fill;
+    type Cursor = String;
+
+    fn cursor(item: &Fill) -> Option<String> {
+        item.bill_id.clone()
+    }
+
+    fn timestamp(item: &Fill) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    /// Instrument type
+    #[serde(default, with = "str_opt")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID
+    #[serde(default, with = "str_opt")]
+    pub inst_id: MaybeString,
+    /// Last trade ID
+    #[serde(default, with = "str_opt")]
+    pub trade_id: MaybeString,
+    /// Order ID
+    #[serde(default, with = "str_opt")]
+    pub ord_id: MaybeString,
+    /// Client Order ID as assigned by the client
+    #[serde(default, with = "str_opt")]
+    pub cl_ord_id: MaybeString,
+    /// Bill ID
+    #[serde(default, with = "str_opt")]
+    pub bill_id: MaybeString,
+    /// Transaction type
+    #[serde(default, with = "str_opt")]
+    pub sub_type: MaybeString,
+    /// Order tag
+    #[serde(default, with = "str_opt")]
+    pub tag: MaybeString,
+    /// Last filled price
+    #[serde(default, with = "str_opt")]
+    pub fill_px: MaybeFloat,
+    /// Last filled quantity
+    #[serde(default, with = "str_opt")]
+    pub fill_sz: MaybeFloat,
+    /// Index price at the moment of trade execution
+    #[serde(default, with = "str_opt")]
+    pub fill_idx_px: MaybeFloat,
+    /// Last filled profit and loss, applicable to orders which have a trade and aim to close position.
+    #[serde(default, with = "str_opt")]
+    pub fill_pnl: MaybeFloat,
+    /// Implied volatility when filled, only applicable to options
+    #[serde(default, with = "str_opt")]
+    pub fill_px_vol: MaybeFloat,
+    /// Options price when filled, in the unit of USD, only applicable to options
+    #[serde(default, with = "str_opt")]
+    pub fill_px_usd: MaybeFloat,
+    /// Mark volatility when filled, only applicable to options
+    #[serde(default, with = "str_opt")]
+    pub fill_mark_vol: MaybeFloat,
+    /// Forward price when filled, only applicable to options
+    #[serde(default, with = "str_opt")]
+    pub fill_fwd_px: MaybeFloat,
+    /// Mark price when filled, applicable to FUTURES/SWAP/OPTIONS
+    #[serde(default, with = "str_opt")]
+    pub fill_mark_px: MaybeFloat,
+    /// Order side
+    #[serde(default, with = "str_opt")]
+    pub side: Option<Side>,
+    /// Position side
+    #[serde(default, with = "str_opt")]
+    pub pos_side: Option<PositionSide>,
+    /// Liquidity taker or maker
+    #[serde(default, with = "str_opt")]
+    pub exec_type: Option<ExecType>,
+    /// Trading fee or rebate currency
+    #[serde(default, with = "str_opt")]
+    pub fee_ccy: MaybeString,
+    /// The amount of trading fee or rebate. The trading fee deduction is negative, such as '-0.01'; the rebate is positive, such as '0.01'.
+    #[serde(default, with = "str_opt")]
+    pub fee: MaybeFloat,
+    /// Data generation time, Unix timestamp format in milliseconds, e.g. 1597026383085
+    #[serde(default, with = "str_opt")]
+    pub ts: MaybeU64,
+    /// Trade time which is the same as fillTime for the order channel, Unix timestamp format in milliseconds
+    #[serde(default, with = "str_opt")]
+    pub fill_time: MaybeU64,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::error::Error;
+    use crate::api::tests::{rest, MockTransport};
+    use futures::TryStreamExt;
+    use reqwest::StatusCode;
+
+    const ORDER_DETAILS: &str = r#"{"code":"0","msg":"","data":[{"accFillSz":"0.00192834","algoClOrdId":"","algoId":"","attachAlgoClOrdId":"","attachAlgoOrds":[],"avgPx":"51858","cTime":"1708587373361","cancelSource":"","cancelSourceReason":"","category":"normal","ccy":"","clOrdId":"b15","fee":"-0.00000192834","feeCcy":"BTC","fillPx":"51858","fillSz":"0.00192834","fillTime":"1708587373361","instId":"BTC-USDT","instType":"SPOT","isTpLimit":"false","lever":"","linkedAlgoOrd":{"algoId":""},"ordId":"680800019749904384","ordType":"market","pnl":"0","posSide":"net","px":"","pxType":"","pxUsd":"","pxVol":"","quickMgnType":"","rebate":"0","rebateCcy":"USDT","reduceOnly":"false","side":"buy","slOrdPx":"","slTriggerPx":"","slTriggerPxType":"","source":"","state":"filled","stpId":"","stpMode":"","sz":"100","tag":"","tdMode":"cash","tgtCcy":"quote_ccy","tpOrdPx":"","tpTriggerPx":"","tpTriggerPxType":"","tradeId":"744876980","uTime":"1708587373362"}]}"#;
+
+    pub(crate) fn limit_order(cl_ord_id: &str) -> PlaceOrder {
+        PlaceOrder {
+            inst_id: "BTC-USDT".into(),
+            td_mode: TradeMode::Cash,
+            ccy: None,
+            cl_ord_id: Some(cl_ord_id.to_owned()),
+            tag: None,
+            side: Side::Buy,
+            pos_side: None,
+            ord_type: OrderType::Limit,
+            sz: "0.01".parse().unwrap(),
+            px: Some("20000".parse().unwrap()),
+            reduce_only: None,
+            tgt_ccy: None,
+            ban_amend: None,
+            attach_algo_cl_ord_id: None,
+            tp_trigger_px: None,
+            tp_ord_px: None,
+            sl_trigger_px: None,
+            sl_ord_px: None,
+            tp_trigger_px_type: None,
+            sl_trigger_px_type: None,
+            quick_mgn_type: None,
+            stp_id: None,
+            stp_mode: None,
+        }
+    }
+
+    #[test]
+    fn test_place_order_is_idempotent() {
+        assert!(limit_order("a").is_idempotent());
+        assert!(!limit_order("").is_idempotent());
+        assert!(!PlaceOrder {
+            cl_ord_id: None,
+            ..limit_order("a")
+        }
+        .is_idempotent());
+
+        let orders = PlaceMultipleOrders::try_from(vec![limit_order("a"), limit_order("")]);
+        assert!(!orders.unwrap().is_idempotent());
+    }
+
+    #[tokio::test]
+    async fn test_place_multiple_orders() {
+        let too_many: Vec<_> = (0..21).map(|i| limit_order(&i.to_string())).collect();
+        let err = PlaceMultipleOrders::try_from(too_many).unwrap_err();
+        assert_eq!(err.to_string(), "a batch holds 1 to 20 orders, got 21");
+        assert!(PlaceMultipleOrders::try_from(vec![]).is_err());
+
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"2","msg":"","data":[{"clOrdId":"a","ordId":"12345689","tag":"","ts":"1695190491421","sCode":"0","sMsg":""},{"clOrdId":"b","ordId":"","tag":"","ts":"1695190491421","sCode":"51008","sMsg":"Order failed. Insufficient balance."}]}"#,
+        )]);
+        let orders =
+            PlaceMultipleOrders::try_from(vec![limit_order("a"), limit_order("b")]).unwrap();
+        let err = rest(&transport).request(orders).await.unwrap_err();
+        assert_eq!(err.path(), Some("/api/v5/trade/batch-orders"));
+        assert_eq!(err.status(), Some(StatusCode::OK));
+        match err {
+            Error::Api(err) => {
+                let results: Vec<PlaceOrderResponse> = err.parse_data().unwrap().unwrap();
+                assert_eq!(results[0].s_code, Some(0));
+                assert_eq!(results[1].s_code, Some(51008));
+                assert!(results[1].ord_id.is_none());
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+        assert!(transport.requests()[0]
+            .body
+            .starts_with(r#"[{"instId":"BTC-USDT""#));
+    }
+
+    #[tokio::test]
+    async fn test_amend_order() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"clOrdId":"","ordId":"12344","ts":"1695190491421","reqId":"b12344","sCode":"0","sMsg":""}]}"#,
+        )]);
+        let amended = rest(&transport)
+            .request(AmendOrder {
+                inst_id: "BTC-USDT".to_owned(),
+                cxl_on_fail: Some(true),
+                ord_id: Some("12344".to_owned()),
+                req_id: Some("b12344".to_owned()),
+                new_sz: Some("2".to_owned()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(amended[0].ord_id.as_deref(), Some("12344"));
+        assert_eq!(amended[0].cl_ord_id, None);
+        assert_eq!(amended[0].req_id.as_deref(), Some("b12344"));
+        assert_eq!(amended[0].s_code, Some(0));
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.url.path(), "/api/v5/trade/amend-order");
+        assert_eq!(
+            request.body,
+            r#"{"instId":"BTC-USDT","cxlOnFail":true,"ordId":"12344","reqId":"b12344","newSz":"2"}"#
+        );
+    }
+
+    #[test]
+    fn test_amend_multiple_orders() {
+        let amend = |cl_ord_id: &str| AmendOrder {
+            inst_id: "BTC-USDT".to_owned(),
+            cl_ord_id: Some(cl_ord_id.to_owned()),
+            new_sz: Some("2".to_owned()),
+            ..Default::default()
+        };
+        let too_many: Vec<_> = (0..21).map(|i| amend(&i.to_string())).collect();
+        let err = AmendMultipleOrders::try_from(too_many).unwrap_err();
+        assert_eq!((err.len, err.max), (21, MAX_BATCH_ORDERS));
+        assert!(AmendMultipleOrders::try_from(vec![]).is_err());
+
+        let orders = AmendMultipleOrders::try_from(vec![amend("a"), amend("b")]).unwrap();
+        assert_eq!(orders.rate_limit_cost(), 2);
+        assert_eq!(
+            serde_json::to_string(&orders).unwrap(),
+            r#"[{"instId":"BTC-USDT","clOrdId":"a","newSz":"2"},{"instId":"BTC-USDT","clOrdId":"b","newSz":"2"}]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_order_details() {
+        let transport = MockTransport::new([
+            (StatusCode::OK, ORDER_DETAILS),
+            (StatusCode::OK, ORDER_DETAILS),
+        ]);
+        let rest = rest(&transport);
+
+        let orders = rest
+            .request(GetOrderDetails {
+                inst_id: "BTC-USDT".to_owned(),
+                ord_id: Some("680800019749904384".to_owned()),
+                cl_ord_id: None,
+            })
+            .await
+            .unwrap();
+        let order = &orders[0];
+        assert_eq!(order.inst_id, "BTC-USDT");
+        assert_eq!(order.cl_ord_id.as_deref(), Some("b15"));
+        assert!(matches!(order.tgt_ccy, Some(QuantityType::QuoteCcy)));
+        assert!(matches!(order.state, Some(OrderState::Filled)));
+        assert_eq!(order.sz, Some("100".parse().unwrap()));
+        assert_eq!(order.fill_time, Some(1708587373361));
+        assert_eq!(order.px, None);
+
+        rest.request(GetOrderDetails {
+            inst_id: "BTC-USDT".to_owned(),
+            ord_id: None,
+            cl_ord_id: Some("b15".to_owned()),
+        })
+        .await
+        .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(
+            requests[0].url.query(),
+            Some("instId=BTC-USDT&ordId=680800019749904384")
+        );
+        assert_eq!(requests[1].url.query(), Some("instId=BTC-USDT&clOrdId=b15"));
+    }
+
+    #[tokio::test]
+    async fn test_get_fills() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"side":"buy","fillSz":"0.00192834","fillPx":"51858","fillPxVol":"","fillFwdPx":"","fee":"-0.00000192834","fillPnl":"0","ordId":"680800019749904384","feeRate":"-0.001","instType":"SPOT","fillPxUsd":"","instId":"BTC-USDT","clOrdId":"","posSide":"net","billId":"680800019754098688","subType":"1","fillMarkVol":"","tag":"","fillTime":"1708587373361","execType":"T","fillIdxPx":"","tradeId":"744876980","fillMarkPx":"","feeCcy":"BTC","ts":"1708587373362"}]}"#,
+        )]);
+        let fills = rest(&transport)
+            .request(GetFills {
+                inst_type: Some(InstrumentType::Spot),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(fills[0].trade_id.as_deref(), Some("744876980"));
+        assert_eq!(fills[0].fill_px, Some(51858.0));
+        assert_eq!(fills[0].fee_ccy.as_deref(), Some("BTC"));
+        assert!(matches!(fills[0].exec_type, Some(ExecType::Taker)));
+        assert_eq!(fills[0].fill_time, Some(1708587373361));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=SPOT&limit=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_fills_history() {
+        let transport = MockTransport::new([
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"side":"buy","fillSz":"0.00192834","fillPx":"51858","fillPxVol":"","fillFwdPx":"","fee":"-0.00000192834","fillPnl":"0","ordId":"680800019749904384","feeRate":"-0.001","instType":"SPOT","fillPxUsd":"","instId":"BTC-USDT","clOrdId":"","posSide":"net","billId":"680800019754098690","subType":"1","fillMarkVol":"","tag":"","fillTime":"1708587373363","execType":"T","fillIdxPx":"","tradeId":"744876982","fillMarkPx":"","feeCcy":"BTC","ts":"1708587373364"},{"side":"buy","fillSz":"0.00192834","fillPx":"51858","fillPxVol":"","fillFwdPx":"","fee":"-0.00000192834","fillPnl":"0","ordId":"680800019749904384","feeRate":"-0.001","instType":"SPOT","fillPxUsd":"","instId":"BTC-USDT","clOrdId":"","posSide":"net","billId":"680800019754098689","subType":"1","fillMarkVol":"","tag":"","fillTime":"1708587373362","execType":"T","fillIdxPx":"","tradeId":"744876981","fillMarkPx":"","feeCcy":"BTC","ts":"1708587373363"}]}"#,
+            ),
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"side":"buy","fillSz":"0.00192834","fillPx":"51858","fillPxVol":"","fillFwdPx":"","fee":"-0.00000192834","fillPnl":"0","ordId":"680800019749904384","feeRate":"-0.001","instType":"SPOT","fillPxUsd":"","instId":"BTC-USDT","clOrdId":"","posSide":"net","billId":"680800019754098688","subType":"1","fillMarkVol":"","tag":"","fillTime":"1708587373361","execType":"T","fillIdxPx":"","tradeId":"744876980","fillMarkPx":"","feeCcy":"BTC","ts":"1708587373362"}]}"#,
+            ),
+        ]);
+        let req = GetFillsHistory {
+            inst_type: InstrumentType::Spot,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let fills: Vec<_> = rest(&transport)
+            .paginate(req, None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let trade_ids: Vec<_> = fills.iter().map(|fill| fill.trade_id.as_deref()).collect();
+        assert_eq!(
+            trade_ids,
+            [Some("744876982"), Some("744876981"), Some("744876980")]
+        );
+        let requests = transport.requests();
+        assert_eq!(requests[0].url.path(), "/api/v5/trade/fills-history");
+        assert_eq!(requests[0].url.query(), Some("instType=SPOT&limit=2"));
+        assert_eq!(
+            requests[1].url.query(),
+            Some("instType=SPOT&after=680800019754098689&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history_archive() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instType":"FUTURES","instId":"BTC-USD-200329","ccy":"","ordId":"312269865356374016","clOrdId":"b1","tag":"","px":"999","sz":"3","ordType":"limit","side":"buy","posSide":"long","tdMode":"isolated","accFillSz":"0","fillPx":"0","tradeId":"0","fillSz":"0","fillTime":"0","state":"filled","avgPx":"0","lever":"20","attachAlgoClOrdId":"","tpTriggerPx":"","tpTriggerPxType":"","tpOrdPx":"","slTriggerPx":"","slTriggerPxType":"","slOrdPx":"","attachAlgoOrds":[{"attachAlgoId":"681096944655273984","attachAlgoClOrdId":"","tpOrdKind":"condition","tpTriggerPx":"1100","tpTriggerPxType":"last","tpOrdPx":"-1","slTriggerPx":"","slTriggerPxType":"","slOrdPx":"","sz":"","amendPxOnTriggerType":"0","failCode":"","failReason":""}],"linkedAlgoOrd":{"algoId":""},"stpId":"","stpMode":"","feeCcy":"","fee":"0","rebateCcy":"","source":"","rebate":"0","tgtCcy":"","pnl":"0","category":"normal","reduceOnly":"false","cancelSource":"","cancelSourceReason":"","algoClOrdId":"","algoId":"","uTime":"1597026383085","cTime":"1597026383085"}]}"#,
+        )]);
+        let orders = rest(&transport)
+            .request(GetOrderHistoryArchive {
+                inst_type: InstrumentType::Futures,
+                uly: None,
+                inst_family: None,
+                inst_id: None,
+                ord_type: Some(OrderType::Limit),
+                state: None,
+                category: None,
+                after: None,
+                before: None,
+                begin: None,
+                end: None,
+                limit: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=FUTURES&ordType=limit")
+        );
+
+        let order = &orders[0];
+        assert_eq!(order.ord_id.as_deref(), Some("312269865356374016"));
+        assert_eq!(order.sz, Some(3.0));
+        assert!(matches!(order.state, Some(OrderState::Filled)));
+        assert_eq!(order.reduce_only, Some(false));
+        assert_eq!(order.rebate.as_deref(), Some("0"));
+        assert_eq!(order.u_time, Some(1597026383085));
+        assert_eq!(order.attach_algo_ords[0].tp_trigger_px, Some(1100.0));
+        assert_eq!(order.attach_algo_ords[0].tp_ord_px, Some(-1.0));
+        assert!(order.linked_algo_ord.as_ref().unwrap().algo_id.is_none());
+    }
+}
@@ -1,11 +1,17 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
-use crate::api::v5::Request;
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
 
 pub mod rest {
 
-    use crate::api::v5::model::{CandleOHLC, Instrument, InstrumentType, OKXSystemTime};
+    use crate::api::v5::model::{
+        CandleOHLC, DeliveryExerciseHistory, EstimatedPrice, FundingRate, FundingRateHistory,
+        Instrument, InstrumentType, MarkPrice, OKXSystemTime, OpenInterest, OptionSummary,
+        PositionTier, PriceLimit, TradeMode,
+    };
 
     use super::*;
 
@@ -25,6 +31,11 @@ pub mod rest {
         const METHOD: Method = Method::GET;
         const PATH: &'static str = "/public/time";
         const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            10,
+            Duration::from_secs(2),
+            RateLimitRule::Ip,
+        ));
 
         type Response = Vec<OKXSystemTime>;
     }
@@ -58,7 +69,16 @@ pub mod rest {
         const METHOD: Method = Method::GET;
         const PATH: &'static str = "/public/instruments";
         const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentType,
+        ));
         type Response = Vec<Instrument>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            Some(self.inst_type.to_string())
+        }
     }
 
     /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-mark-price-candlesticks
@@ -95,7 +115,642 @@ pub mod rest {
         const METHOD: Method = Method::GET;
         const PATH: &'static str = "/market/mark-price-candles";
         const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::Ip,
+        ));
 
         type Response = Vec<CandleOHLC>;
     }
+
+    impl Paginated for GetMarkPriceCandles {
+        type Item = CandleOHLC;
+        type Cursor = u64;
+
+        fn cursor(item: &CandleOHLC) -> Option<u64> {
+            item.ts
+        }
+
+        fn timestamp(item: &CandleOHLC) -> Option<u64> {
+            item.ts
+        }
+
+        fn set_after(&mut self, cursor: u64) {
+            self.after = Some(cursor);
+        }
+
+        fn limit(&self) -> Option<usize> {
+            self.limit
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-funding-rate
+    /// ## Get funding rate
+    /// Retrieve funding rate.
+    ///
+    /// Rate Limit: 20 requests per 2 seconds \
+    /// Rate limit rule: IP + Instrument ID
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/funding-rate
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetFundingRate {
+        /// Instrument ID, e.g. BTC-USD-SWAP, only applicable to SWAP
+        pub inst_id: String,
+    }
+
+    impl Request for GetFundingRate {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/funding-rate";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentId,
+        ));
+        type Response = Vec<FundingRate>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            Some(self.inst_id.clone())
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-funding-rate-history
+    /// ## Get funding rate history
+    /// Retrieve funding rate history. This endpoint can retrieve data from the last 3 months.
+    ///
+    /// Rate Limit: 10 requests per 2 seconds \
+    /// Rate limit rule: IP + Instrument ID
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/funding-rate-history
+    #[derive(Debug, Clone, Serialize, Default)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetFundingRateHistory {
+        /// Instrument ID, e.g. BTC-USD-SWAP, only applicable to SWAP
+        pub inst_id: String,
+        /// Pagination of data to return records earlier than the requested fundingTime
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<u64>,
+        /// Pagination of data to return records newer than the requested fundingTime
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<u64>,
+        /// Number of results per request. The maximum is 100; The default is 100
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<usize>,
+    }
+
+    impl Request for GetFundingRateHistory {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/funding-rate-history";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            10,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentId,
+        ));
+        type Response = Vec<FundingRateHistory>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            Some(self.inst_id.clone())
+        }
+    }
+
+    impl Paginated for GetFundingRateHistory {
+        type Item = FundingRateHistory;
+        type Cursor = u64;
+
+        fn cursor(item: &FundingRateHistory) -> Option<u64> {
+            item.funding_time
+        }
+
+        fn timestamp(item: &FundingRateHistory) -> Option<u64> {
+            item.funding_time
+        }
+
+        fn set_after(&mut self, cursor: u64) {
+            self.after = Some(cursor);
+        }
+
+        fn limit(&self) -> Option<usize> {
+            self.limit
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-open-interest
+    /// ## Get open interest
+    /// Retrieve the total open interest for contracts on OKX.
+    ///
+    /// Rate Limit: 20 requests per 2 seconds \
+    /// Rate limit rule: IP + Instrument ID
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/open-interest
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetOpenInterest {
+        /// Instrument type: SWAP, FUTURES, OPTION
+        pub inst_type: InstrumentType,
+        /// Underlying, applicable to FUTURES/SWAP/OPTION
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uly: Option<String>,
+        /// Instrument family, applicable to FUTURES/SWAP/OPTION
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_family: Option<String>,
+        /// Instrument ID, e.g. BTC-USDT-SWAP
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_id: Option<String>,
+    }
+
+    impl Request for GetOpenInterest {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/open-interest";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentId,
+        ));
+        type Response = Vec<OpenInterest>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            self.inst_id.clone()
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-limit-price
+    /// ## Get limit price
+    /// Retrieve the highest buy limit and lowest sell limit of the instrument.
+    ///
+    /// Rate Limit: 20 requests per 2 seconds \
+    /// Rate limit rule: IP
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/price-limit
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetPriceLimit {
+        /// Instrument ID, e.g. BTC-USDT-SWAP
+        pub inst_id: String,
+    }
+
+    impl Request for GetPriceLimit {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/price-limit";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::Ip,
+        ));
+        type Response = Vec<PriceLimit>;
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-mark-price
+    /// ## Get mark price
+    /// Retrieve mark price.
+    ///
+    /// We set the mark price based on the SPOT index and at a reasonable basis to prevent individual users from manipulating the market and causing the contract price to fluctuate.
+    ///
+    /// Rate Limit: 10 requests per 2 seconds \
+    /// Rate limit rule: IP + Instrument ID
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/mark-price
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetMarkPrice {
+        /// Instrument type: MARGIN, SWAP, FUTURES, OPTION
+        pub inst_type: InstrumentType,
+        /// Underlying, applicable to FUTURES/SWAP/OPTION
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uly: Option<String>,
+        /// Instrument family, applicable to FUTURES/SWAP/OPTION
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_family: Option<String>,
+        /// Instrument ID, e.g. BTC-USD-SWAP
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_id: Option<String>,
+    }
+
+    impl Request for GetMarkPrice {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/mark-price";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            10,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentId,
+        ));
+        type Response = Vec<MarkPrice>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            self.inst_id.clone()
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-position-tiers
+    /// ## Get position tiers
+    /// Retrieve position tiers information, maximum leverage depends on your borrowings and margin ratio.
+    ///
+    /// Rate Limit: 10 requests per 2 seconds \
+    /// Rate limit rule: IP
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/position-tiers
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetPositionTiers {
+        /// Instrument type: MARGIN, SWAP, FUTURES, OPTION
+        pub inst_type: InstrumentType,
+        /// Trade mode, margin mode cross or isolated
+        pub td_mode: TradeMode,
+        /// Single underlying or multiple underlyings (no more than 3) separated with comma.
+        /// If instType is SWAP/FUTURES/OPTION, either uly or instFamily is required.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uly: Option<String>,
+        /// Single instrument family or multiple instrument families (no more than 5) separated with comma.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_family: Option<String>,
+        /// Single instrument or multiple instruments (no more than 5) separated with comma, only applicable to MARGIN
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_id: Option<String>,
+        /// Margin currency, only applicable to cross MARGIN
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub ccy: Option<String>,
+        /// Tiers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tier: Option<String>,
+    }
+
+    impl Request for GetPositionTiers {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/position-tiers";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            10,
+            Duration::from_secs(2),
+            RateLimitRule::Ip,
+        ));
+        type Response = Vec<PositionTier>;
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-estimated-delivery-exercise-price
+    /// ## Get estimated delivery/exercise price
+    /// Retrieve the estimated delivery price which will only have a return value one hour before the delivery/exercise.
+    ///
+    /// Rate Limit: 10 requests per 2 seconds \
+    /// Rate limit rule: IP + Instrument ID
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/estimated-price
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetEstimatedPrice {
+        /// Instrument ID, e.g. BTC-USD-200214, only applicable to FUTURES/OPTION
+        pub inst_id: String,
+    }
+
+    impl Request for GetEstimatedPrice {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/estimated-price";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            10,
+            Duration::from_secs(2),
+            RateLimitRule::IpInstrumentId,
+        ));
+        type Response = Vec<EstimatedPrice>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            Some(self.inst_id.clone())
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-delivery-exercise-history
+    /// ## Get delivery/exercise history
+    /// Retrieve delivery records of Futures and exercise records of Options in the last 3 months.
+    ///
+    /// Rate Limit: 40 requests per 2 seconds \
+    /// Rate limit rule: IP + (Instrument Type + Underlying)
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/delivery-exercise-history
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetDeliveryExerciseHistory {
+        /// Instrument type: FUTURES, OPTION
+        pub inst_type: InstrumentType,
+        /// Underlying, either uly or instFamily is required
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uly: Option<String>,
+        /// Instrument family, either uly or instFamily is required
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_family: Option<String>,
+        /// Pagination of data to return records earlier than the requested ts
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub after: Option<u64>,
+        /// Pagination of data to return records newer than the requested ts
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub before: Option<u64>,
+        /// Number of results per request. The maximum is 100; The default is 100
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub limit: Option<usize>,
+    }
+
+    impl Request for GetDeliveryExerciseHistory {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/delivery-exercise-history";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            40,
+            Duration::from_secs(2),
+            RateLimitRule::IpUnderlying,
+        ));
+        type Response = Vec<DeliveryExerciseHistory>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            let uly = self.uly.as_ref().or(self.inst_family.as_ref());
+            Some(format!(
+                "{}:{}",
+                self.inst_type,
+                uly.map_or("", String::as_str)
+            ))
+        }
+    }
+
+    impl Paginated for GetDeliveryExerciseHistory {
+        type Item = DeliveryExerciseHistory;
+        type Cursor = u64;
+
+        fn cursor(item: &DeliveryExerciseHistory) -> Option<u64> {
+            item.ts
+        }
+
+        fn timestamp(item: &DeliveryExerciseHistory) -> Option<u64> {
+            item.ts
+        }
+
+        fn set_after(&mut self, cursor: u64) {
+            self.after = Some(cursor);
+        }
+
+        fn limit(&self) -> Option<usize> {
+            self.limit
+        }
+    }
+
+    /// https://www.okx.com/docs-v5/en/#public-data-rest-api-get-option-market-data
+    /// ## Get option market data
+    /// Retrieve option market data.
+    ///
+    /// Rate Limit: 20 requests per 2 seconds \
+    /// Rate limit rule: IP + Underlying
+    ///
+    /// ### HTTP Request
+    /// **GET** /api/v5/public/opt-summary
+    #[derive(Debug, Clone, Serialize, Default)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GetOptionSummary {
+        /// Underlying, only applicable to OPTION, either uly or instFamily is required
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uly: Option<String>,
+        /// Instrument family, only applicable to OPTION, either uly or instFamily is required
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inst_family: Option<String>,
+        /// Contract expiry date, the format is "YYMMDD", e.g. "200527"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub exp_time: Option<String>,
+    }
+
+    impl Request for GetOptionSummary {
+        const METHOD: Method = Method::GET;
+        const PATH: &'static str = "/public/opt-summary";
+        const AUTH: bool = false;
+        const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+            20,
+            Duration::from_secs(2),
+            RateLimitRule::IpUnderlying,
+        ));
+        type Response = Vec<OptionSummary>;
+
+        fn rate_limit_key(&self) -> Option<String> {
+            self.uly.clone().or_else(|| self.inst_family.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rest::*;
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::model::{DeliveryExerciseHistoryType, InstrumentType, TradeMode};
+    use futures::TryStreamExt;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_system_time() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ts":"1597026383085"}]}"#,
+        )]);
+        let time = rest(&transport).request(GetSystemTime).await.unwrap();
+        assert_eq!(time[0].ts, Some(1597026383085));
+    }
+
+    #[tokio::test]
+    async fn test_get_funding_rate() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"fundingRate":"0.0000792386885340","fundingTime":"1703088000000","instId":"BTC-USDT-SWAP","instType":"SWAP","method":"next_period","nextFundingRate":"","nextFundingTime":"1703116800000","settState":"settled","ts":"1703070685309"}]}"#,
+        )]);
+        let rates = rest(&transport)
+            .request(GetFundingRate {
+                inst_id: "BTC-USDT-SWAP".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(rates[0].inst_type, Some(InstrumentType::Swap));
+        assert_eq!(rates[0].funding_rate, Some(0.000079238688534));
+        assert_eq!(rates[0].next_funding_rate, None);
+        assert_eq!(rates[0].next_funding_time, Some(1703116800000));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT-SWAP")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_funding_rate_history() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"fundingRate":"0.0000792386885340","fundingTime":"1703088000000","instId":"BTC-USDT-SWAP","instType":"SWAP","method":"next_period","realizedRate":"0.0000792386885340"},{"fundingRate":"0.0000569","fundingTime":"1703059200000","instId":"BTC-USDT-SWAP","instType":"SWAP","method":"next_period","realizedRate":"0.0000569"}]}"#,
+        )]);
+        let rates: Vec<_> = rest(&transport)
+            .paginate(
+                GetFundingRateHistory {
+                    inst_id: "BTC-USDT-SWAP".to_owned(),
+                    ..Default::default()
+                },
+                Some(1703080000000),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].realized_rate, Some(0.000079238688534));
+    }
+
+    #[tokio::test]
+    async fn test_get_open_interest() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT-SWAP","instType":"SWAP","oi":"2125419","oiCcy":"21254.19","oiUsd":"913213485.2","ts":"1703069542515"}]}"#,
+        )]);
+        let interest = rest(&transport)
+            .request(GetOpenInterest {
+                inst_type: InstrumentType::Swap,
+                uly: None,
+                inst_family: None,
+                inst_id: Some("BTC-USDT-SWAP".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(interest[0].oi, Some(2125419.0));
+        assert_eq!(interest[0].oi_ccy, Some(21254.19));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=SWAP&instId=BTC-USDT-SWAP")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_price_limit() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"buyLmt":"17057.9","enabled":true,"instId":"BTC-USDT-SWAP","instType":"SWAP","sellLmt":"16388.9","ts":"1597026383085"}]}"#,
+        )]);
+        let limits = rest(&transport)
+            .request(GetPriceLimit {
+                inst_id: "BTC-USDT-SWAP".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(limits[0].buy_lmt, Some(17057.9));
+        assert_eq!(limits[0].sell_lmt, Some(16388.9));
+        assert_eq!(limits[0].enabled, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_mark_price() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instType":"SWAP","instId":"BTC-USDT-SWAP","markPx":"43321.5","ts":"1597026383085"}]}"#,
+        )]);
+        let prices = rest(&transport)
+            .request(GetMarkPrice {
+                inst_type: InstrumentType::Swap,
+                uly: None,
+                inst_family: None,
+                inst_id: Some("BTC-USDT-SWAP".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(prices[0].inst_id.as_deref(), Some("BTC-USDT-SWAP"));
+        assert_eq!(prices[0].mark_px, Some(43321.5));
+    }
+
+    #[tokio::test]
+    async fn test_get_position_tiers() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"baseMaxLoan":"","imr":"0.01","instFamily":"BTC-USDT","instId":"","maxLever":"100","maxSz":"500","minSz":"0","mmr":"0.004","optMgnFactor":"0","quoteMaxLoan":"","tier":"1","uly":"BTC-USDT"}]}"#,
+        )]);
+        let tiers = rest(&transport)
+            .request(GetPositionTiers {
+                inst_type: InstrumentType::Swap,
+                td_mode: TradeMode::Cross,
+                uly: None,
+                inst_family: Some("BTC-USDT".to_owned()),
+                inst_id: None,
+                ccy: None,
+                tier: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(tiers[0].tier, Some(1));
+        assert_eq!(tiers[0].max_lever, Some(100.0));
+        assert_eq!(tiers[0].base_max_loan, None);
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=SWAP&tdMode=cross&instFamily=BTC-USDT")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_estimated_price() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instType":"FUTURES","instId":"BTC-USDT-201227","settlePx":"200","ts":"1597026383085"}]}"#,
+        )]);
+        let prices = rest(&transport)
+            .request(GetEstimatedPrice {
+                inst_id: "BTC-USDT-201227".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(prices[0].inst_type, Some(InstrumentType::Futures));
+        assert_eq!(prices[0].settle_px, Some(200.0));
+    }
+
+    #[tokio::test]
+    async fn test_get_delivery_exercise_history() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ts":"1597026383085","details":[{"type":"delivery","insId":"BTC-USD-190927","px":"0.016"},{"type":"expired_otm","insId":"BTC-USD-190927-9000-C","px":""}]}]}"#,
+        )]);
+        let history = rest(&transport)
+            .request(GetDeliveryExerciseHistory {
+                inst_type: InstrumentType::Futures,
+                uly: Some("BTC-USD".to_owned()),
+                inst_family: None,
+                after: None,
+                before: None,
+                limit: None,
+            })
+            .await
+            .unwrap();
+        let details = &history[0].details;
+        assert_eq!(
+            details[0].r#type,
+            Some(DeliveryExerciseHistoryType::Delivery)
+        );
+        assert_eq!(details[1].px, None);
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=FUTURES&uly=BTC-USD")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_option_summary() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"askVol":"0","bidVol":"0","delta":"0.7","deltaBS":"0.72","fwdPx":"39016.8","gamma":"-1.1","gammaBS":"0.000028","instId":"BTC-USD-220309-33000-C","instType":"OPTION","lever":"0","markVol":"1.5","realVol":"0","theta":"-0.0005","thetaBS":"-33.5","ts":"1646733631242","uly":"BTC-USD","vega":"0","vegaBS":"0.5","volLv":"1.4"}]}"#,
+        )]);
+        let summary = rest(&transport)
+            .request(GetOptionSummary {
+                uly: Some("BTC-USD".to_owned()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(summary[0].inst_type, Some(InstrumentType::Option));
+        assert_eq!(summary[0].delta, Some(0.7));
+        assert_eq!(summary[0].delta_bs, Some(0.72));
+        assert_eq!(summary[0].mark_vol, Some(1.5));
+        assert_eq!(transport.requests()[0].url.query(), Some("uly=BTC-USD"));
+    }
 }
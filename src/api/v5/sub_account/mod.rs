@@ -0,0 +1,413 @@
+use crate::api::v5::model::{
+    AccountType, FundingBalance, SubAccount, SubAccountBill, SubAccountBillType,
+    SubAccountTransferOut, SubAccountTransferResponse, TradingBalanceDetail,
+};
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-get-sub-account-list
+/// ## Get sub-account list
+/// Applies to master accounts only
+///
+/// Rate Limit: 2 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/users/subaccount/list
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubAccountList {
+    /// Sub-account status, true: Normal, false: Frozen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable: Option<bool>,
+    /// Sub-account name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_acct: Option<String>,
+    /// Query the data earlier than the requested subaccount creation timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Query the data newer than the requested subaccount creation timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetSubAccountList {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/subaccount/list";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        2,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<SubAccount>;
+}
+
+impl Paginated for GetSubAccountList {
+    type Item = SubAccount;
+    type Cursor = u64;
+
+    fn cursor(item: &SubAccount) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &SubAccount) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-get-sub-account-trading-balance
+/// ## Get sub-account trading balance
+/// Query detailed balance info of Trading Account of a sub-account via the master account (applies to master accounts only)
+///
+/// Rate Limit: 6 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/account/subaccount/balances
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubAccountTradingBalances {
+    /// Sub-account name
+    pub sub_acct: String,
+}
+
+impl Request for GetSubAccountTradingBalances {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/subaccount/balances";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<TradingBalanceDetail>;
+}
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-get-sub-account-funding-balance
+/// ## Get sub-account funding balance
+/// Query detailed balance info of Funding Account of a sub-account via the master account (applies to master accounts only)
+///
+/// Rate Limit: 6 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/subaccount/balances
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubAccountFundingBalances {
+    /// Sub-account name
+    pub sub_acct: String,
+    /// Single currency or multiple currencies (no more than 20) separated with comma, e.g. BTC or BTC,ETH.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+}
+
+impl Request for GetSubAccountFundingBalances {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/subaccount/balances";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<FundingBalance>;
+}
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-master-accounts-manage-the-transfers-between-sub-accounts
+/// ## Master accounts manage the transfers between sub-accounts
+/// Applies to master accounts only.
+///
+/// Only API keys with Trade privilege can call this endpoint.
+///
+/// Rate Limit: 1 request per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **POST** /api/v5/asset/subaccount/transfer
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    /// Currency
+    pub ccy: String,
+    /// Transfer amount
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub amt: f64,
+    /// The remitting account of the sub-account
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub from: AccountType,
+    /// The beneficiary account of the sub-account
+    #[serde(serialize_with = "crate::serde_util::serialize_as_str")]
+    pub to: AccountType,
+    /// Sub-account name of the account that transfers funds out
+    pub from_sub_account: String,
+    /// Sub-account name of the account that transfers funds in
+    pub to_sub_account: String,
+    /// Whether or not borrowed coins can be transferred out under Multi-currency margin/Portfolio margin
+    /// The default is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_trans: Option<bool>,
+    /// Ignore position risk, only applicable to Portfolio margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_pos_risk: Option<String>,
+}
+
+impl Request for SubAccountTransfer {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/asset/subaccount/transfer";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        1,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<SubAccountTransferResponse>;
+}
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-history-of-sub-account-transfer
+/// ## History of sub-account transfer
+/// This endpoint is only available for master accounts. Transfer records are available from September 28, 2022 onwards.
+///
+/// Rate Limit: 6 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **GET** /api/v5/asset/subaccount/bills
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubAccountBills {
+    /// Currency, such as BTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Transfer type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<SubAccountBillType>,
+    /// Sub-account name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_acct: Option<String>,
+    /// Query the data prior to the requested bill ID creation time (exclude), Unix timestamp in millisecond format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Query the data after the requested bill ID creation time (exclude), Unix timestamp in millisecond format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetSubAccountBills {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/asset/subaccount/bills";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        6,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<SubAccountBill>;
+}
+
+impl Paginated for GetSubAccountBills {
+    type Item = SubAccountBill;
+    type Cursor = u64;
+
+    fn cursor(item: &SubAccountBill) -> Option<u64> {
+        item.ts
+    }
+
+    fn timestamp(item: &SubAccountBill) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#sub-account-rest-api-set-permission-of-transfer-out
+/// ## Set permission of transfer out
+/// Set permission of transfer out for sub-account (only applicable to master account API key).
+/// Sub-account can transfer out to master account by default.
+///
+/// Rate Limit: 1 request per second
+/// Rate limit rule: UserID
+/// ### HTTP Request
+/// **POST** /api/v5/users/subaccount/set-transfer-out
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSubAccountTransferOut {
+    /// Name of the sub-account. Single sub-account or multiple sub-account (no more than 20) separated with comma.
+    pub sub_acct: String,
+    /// Whether the sub-account has the right to transfer out. The default is true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_trans_out: Option<bool>,
+}
+
+impl Request for SetSubAccountTransferOut {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/users/subaccount/set-transfer-out";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        1,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<SubAccountTransferOut>;
+
+    /// Setting the permission again has no further effect
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tests::{rest, MockTransport};
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_sub_account_list() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"canTransOut":false,"enable":true,"frozenFunc":[],"gAuth":false,"label":"D456DDDLx","mobile":"","subAcct":"D456DDDL","ts":"1659334756000","type":"1","uid":"3400***********7413"}]}"#,
+        )]);
+        let accounts = rest(&transport)
+            .request(GetSubAccountList {
+                enable: Some(true),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(accounts[0].sub_acct.as_deref(), Some("D456DDDL"));
+        assert_eq!(accounts[0].enable, Some(true));
+        assert_eq!(accounts[0].can_trans_out, Some(false));
+        assert_eq!(
+            GetSubAccountList::timestamp(&accounts[0]),
+            Some(1659334756000)
+        );
+        assert_eq!(transport.requests()[0].url.query(), Some("enable=true"));
+    }
+
+    #[tokio::test]
+    async fn test_get_sub_account_trading_balances() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"adjEq":"10679688.0460531643092577","borrowFroz":"","details":[],"imr":"","isoEq":"0","mgnRatio":"","mmr":"","notionalUsd":"","ordFroz":"","totalEq":"10679688.0460531643092577","uTime":"1620963720131"}]}"#,
+        )]);
+        let balances = rest(&transport)
+            .request(GetSubAccountTradingBalances {
+                sub_acct: "test-1".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(balances[0].u_time, Some(1620963720131));
+        assert_eq!(balances[0].iso_eq, "0".parse().ok());
+        assert!(balances[0].details.is_empty());
+        assert_eq!(transport.requests()[0].url.query(), Some("subAcct=test-1"));
+    }
+
+    #[tokio::test]
+    async fn test_get_sub_account_funding_balances() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"availBal":"37.11827078","bal":"37.11827078","ccy":"ETH","frozenBal":"0"}]}"#,
+        )]);
+        let balances = rest(&transport)
+            .request(GetSubAccountFundingBalances {
+                sub_acct: "test-1".to_owned(),
+                ccy: Some("ETH".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(balances[0].ccy, "ETH");
+        assert_eq!(balances[0].avail_bal, Some(37.11827078));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("subAcct=test-1&ccy=ETH")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sub_account_transfer() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"transId":"12345"}]}"#,
+        )]);
+        let transfer = rest(&transport)
+            .request(SubAccountTransfer {
+                ccy: "USDT".to_owned(),
+                amt: 1.5,
+                from: AccountType::Funding,
+                to: AccountType::Trading,
+                from_sub_account: "test-1".to_owned(),
+                to_sub_account: "test-2".to_owned(),
+                loan_trans: None,
+                omit_pos_risk: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(transfer[0].trans_id.as_deref(), Some("12345"));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"ccy":"USDT","amt":"1.5","from":"6","to":"18","fromSubAccount":"test-1","toSubAccount":"test-2"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_sub_account_bills() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"billId":"12344","type":"1","ccy":"BTC","amt":"2","subAcct":"test-1","ts":"1597026383085"}]}"#,
+        )]);
+        let bills = rest(&transport)
+            .request(GetSubAccountBills {
+                r#type: Some(SubAccountBillType::SubAccountToMaster),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            bills[0].r#type,
+            Some(SubAccountBillType::SubAccountToMaster)
+        );
+        assert_eq!(bills[0].amt, Some(2.0));
+        assert_eq!(transport.requests()[0].url.query(), Some("type=1"));
+    }
+
+    #[tokio::test]
+    async fn test_set_sub_account_transfer_out() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"subAcct":"test-1","canTransOut":true}]}"#,
+        )]);
+        let set = SetSubAccountTransferOut {
+            sub_acct: "test-1".to_owned(),
+            can_trans_out: Some(true),
+        };
+        assert!(set.is_idempotent());
+        let results = rest(&transport).request(set).await.unwrap();
+        assert_eq!(results[0].sub_acct.as_deref(), Some("test-1"));
+        assert_eq!(results[0].can_trans_out, Some(true));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"subAcct":"test-1","canTransOut":true}"#
+        );
+    }
+}
@@ -1,8 +1,15 @@
-use crate::api::v5::model::TradingBalanceDetail;
-use crate::api::v5::Request;
+use crate::api::v5::model::{
+    AccountConfig, Bill, BillSubType, BillType, InstrumentType, InterestAccrued, InterestRate,
+    IsolatedMarginMode, IsolatedModeConfig, Leverage, MarginBalance, MarginBalanceType, MarginMode,
+    MaxAvailSize, MaxSize, Position, PositionHistory, PositionMode, PositionModeConfig,
+    PositionRisk, PositionSide, TradeFee, TradeMode, TradingBalanceDetail,
+};
+use crate::api::v5::{Paginated, Request};
+use crate::api::{RateLimit, RateLimitRule};
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 use super::*;
 
@@ -27,5 +34,1064 @@ impl Request for GetTradingBalances {
     const METHOD: Method = Method::GET;
     const PATH: &'static str = "/account/balance";
     const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
     type Response = Vec<TradingBalanceDetail>;
 }
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-positions
+/// ## Get positions
+/// Retrieve information on your positions. When the account is in net mode, net positions will be displayed,
+/// and when the account is in long/short mode, long or short positions will be displayed.
+/// Return in reverse chronological order using ctime.
+///
+/// Rate Limit: 10 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/positions
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositions {
+    /// Instrument type `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    /// instId will be checked against instType when both parameters are passed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT-SWAP. Single instrument ID or multiple instrument IDs (no more than 10) separated with comma
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Single position ID or multiple position IDs (no more than 20) separated with comma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_id: Option<String>,
+}
+
+impl Request for GetPositions {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/positions";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<Position>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-positions-history
+/// ## Get positions history
+/// Retrieve the updated position data for the last 3 months. Return in reverse chronological order using utime.
+///
+/// [`Paginated`] on `uTime`: positions updated in the same millisecond as the last one of a page
+/// are skipped by the next page, e.g. the positions closed together by one liquidation.
+///
+/// Rate Limit: 10 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/positions-history
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositionsHistory {
+    /// Instrument type `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USD-SWAP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Margin mode, cross or isolated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mgn_mode: Option<MarginMode>,
+    /// The type of latest close position
+    /// 1: Close position partially; 2: Close all; 3: Liquidation; 4: Partial liquidation; 5: ADL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Position ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_id: Option<String>,
+    /// Pagination of data to return records earlier than the requested uTime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested uTime
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetPositionsHistory {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/positions-history";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<PositionHistory>;
+}
+
+impl Paginated for GetPositionsHistory {
+    type Item = PositionHistory;
+    type Cursor = u64;
+
+    fn cursor(item: &PositionHistory) -> Option<u64> {
+        item.u_time
+    }
+
+    fn timestamp(item: &PositionHistory) -> Option<u64> {
+        item.u_time
+    }
+
+    fn set_after(&mut self, cursor: u64) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-account-and-position-risk
+/// ## Get account and position risk
+/// Get account and position risks
+///
+/// Rate Limit: 10 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/account-position-risk
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccountPositionRisk {
+    /// Instrument type `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+}
+
+impl Request for GetAccountPositionRisk {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/account-position-risk";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        10,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<PositionRisk>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-account-configuration
+/// ## Get account configuration
+/// Retrieve current account configuration.
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/config
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccountConfig {}
+
+impl Request for GetAccountConfig {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/config";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<AccountConfig>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-set-position-mode
+/// ## Set position mode
+/// Single-currency mode and Multi-currency mode: FUTURES and SWAP support both long/short mode and net mode.
+/// In net mode, users can only have positions in one direction; In long/short mode, users can hold positions in long and short directions.
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **POST** /api/v5/account/set-position-mode
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPositionMode {
+    /// Position mode, long_short_mode or net_mode
+    pub pos_mode: PositionMode,
+}
+
+impl Request for SetPositionMode {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/account/set-position-mode";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<PositionModeConfig>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-set-leverage
+/// ## Set leverage
+/// Set the leverage of an instrument, or of a currency for cross MARGIN.
+///
+/// Rate Limit: 20 requests per 2 seconds
+/// Rate limit rule: UserID + Instrument ID
+/// ### HTTP Requests
+/// **POST** /api/v5/account/set-leverage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverage {
+    /// Instrument ID
+    /// Either instId or ccy is required; if both are passed, instId will be used by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Currency used for margin, only applicable to cross MARGIN of Multi-currency margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Leverage
+    pub lever: String,
+    /// Margin mode, isolated or cross
+    pub mgn_mode: MarginMode,
+    /// Position side, long or short
+    /// Only required when margin mode is isolated in long/short mode for FUTURES/SWAP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_side: Option<PositionSide>,
+}
+
+impl Request for SetLeverage {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/account/set-leverage";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserIdInstrumentId,
+    ));
+    type Response = Vec<Leverage>;
+
+    fn rate_limit_key(&self) -> Option<String> {
+        self.inst_id.clone().or_else(|| self.ccy.clone())
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-leverage
+/// ## Get leverage
+/// Retrieve the leverage of instruments or currencies.
+///
+/// Rate Limit: 20 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/leverage-info
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLeverageInfo {
+    /// Instrument ID
+    /// Single instrument ID or multiple instrument IDs (no more than 20) separated with comma
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Currency used for margin, only applicable to cross MARGIN of Multi-currency margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Margin mode, isolated or cross
+    pub mgn_mode: MarginMode,
+}
+
+impl Request for GetLeverageInfo {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/leverage-info";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<Leverage>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-maximum-order-quantity
+/// ## Get maximum order quantity
+/// The maximum quantity to buy or sell. It corresponds to the "sz" from placement.
+///
+/// Rate Limit: 20 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/max-size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMaxSize {
+    /// Single instrument or multiple instruments (no more than 5) separated with comma, e.g. BTC-USDT,ETH-USDT
+    pub inst_id: String,
+    /// Trade mode, cross, isolated or cash
+    pub td_mode: TradeMode,
+    /// Currency used for margin, only applicable to MARGIN of Spot and futures mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Price
+    /// When the price is not specified, it will be calculated according to the current limit price for FUTURES and SWAP, the last traded price for other instrument types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub px: Option<String>,
+    /// Leverage for instrument, the default is current leverage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leverage: Option<String>,
+    /// Whether to calculate the maximum quantity without the spot-derivatives risk offset, only applicable to Portfolio margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub un_spot_offset: Option<bool>,
+}
+
+impl Request for GetMaxSize {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/max-size";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<MaxSize>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-maximum-available-balance-equity
+/// ## Get maximum available balance/equity
+/// Available balance for isolated margin positions and SPOT, available equity for cross margin positions.
+///
+/// Rate Limit: 20 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/max-avail-size
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMaxAvailSize {
+    /// Single instrument or multiple instruments (no more than 5) separated with comma, e.g. BTC-USDT,ETH-USDT
+    pub inst_id: String,
+    /// Trade mode, cross, isolated or cash
+    pub td_mode: TradeMode,
+    /// Currency used for margin, only applicable to isolated MARGIN and cross MARGIN orders in Spot and futures mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Whether to reduce position only, only applicable to MARGIN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Whether to calculate the maximum quantity without the spot-derivatives risk offset, only applicable to Portfolio margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub un_spot_offset: Option<bool>,
+    /// Quick Margin type, only applicable to Quick Margin Mode of isolated margin, manual, auto_borrow or auto_repay
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quick_mgn_type: Option<String>,
+}
+
+impl Request for GetMaxAvailSize {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/max-avail-size";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<MaxAvailSize>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-increase-decrease-margin
+/// ## Increase/decrease margin
+/// Increase or decrease the margin of the isolated position. Margin reduction may result in the change of the actual leverage.
+///
+/// Rate Limit: 20 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **POST** /api/v5/account/position/margin-balance
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustMarginBalance {
+    /// Instrument ID
+    pub inst_id: String,
+    /// Position side, the default is net
+    /// long or short in long/short mode, net in net mode
+    pub pos_side: PositionSide,
+    /// add: add margin, reduce: reduce margin
+    pub r#type: MarginBalanceType,
+    /// Amount to be increased or decreased
+    pub amt: String,
+    /// Currency, only applicable to isolated MARGIN orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+}
+
+impl Request for AdjustMarginBalance {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/account/position/margin-balance";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        20,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<MarginBalance>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-isolated-margin-trading-settings
+/// ## Isolated margin trading settings
+/// You can set the currency margin and futures/perpetual Isolated margin trading mode
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **POST** /api/v5/account/set-isolated-mode
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetIsolatedMode {
+    /// Isolated margin trading settings, auto_transfers_ccy or automatic
+    pub iso_mode: IsolatedMarginMode,
+    /// Instrument type, MARGIN or CONTRACTS
+    pub r#type: String,
+}
+
+impl Request for SetIsolatedMode {
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/account/set-isolated-mode";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<IsolatedModeConfig>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-fee-rates
+/// ## Get fee rates
+/// Retrieve the fee rates of the trading account
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/trade-fee
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTradeFee {
+    /// Instrument type `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    pub inst_type: InstrumentType,
+    /// Instrument ID, e.g. BTC-USDT, only applicable to SPOT/MARGIN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Underlying, e.g. BTC-USD, only applicable to FUTURES/SWAP/OPTION
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uly: Option<String>,
+    /// Instrument family, e.g. BTC-USD, only applicable to FUTURES/SWAP/OPTION
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_family: Option<String>,
+}
+
+impl Request for GetTradeFee {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/trade-fee";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<TradeFee>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-bills-details-last-7-days
+/// ## Get bills details (last 7 days)
+/// Retrieve the bills of the account. The bill refers to all transaction records that result in changing the balance of an account.
+/// Pagination is supported, and the response is sorted with the most recent first. This endpoint can retrieve data from the last 7 days.
+///
+/// Rate Limit: 5 requests per second
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/bills
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBills {
+    /// Instrument type `SPOT`, `MARGIN`, `SWAP`, `FUTURES`, `OPTION`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument ID, e.g. BTC-USDT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Bill currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Margin mode, isolated or cross
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mgn_mode: Option<MarginMode>,
+    /// Contract type, linear or inverse, only applicable to FUTURES/SWAP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ct_type: Option<String>,
+    /// Bill type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<BillType>,
+    /// Bill subtype
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<BillSubType>,
+    /// Pagination of data to return records earlier than the requested bill ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Pagination of data to return records newer than the requested bill ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Filter with a begin timestamp, Unix timestamp format in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// Filter with an end timestamp, Unix timestamp format in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetBills {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/bills";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(1),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<Bill>;
+}
+
+impl Paginated for GetBills {
+    type Item = Bill;
+    type Cursor = String;
+
+    fn cursor(item: &Bill) -> Option<String> {
+        item.bill_id.clone()
+    }
+
+    fn timestamp(item: &Bill) -> Option<u64> {
+        item.ts
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.after = Some(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-bills-details-last-3-months
+/// ## Get bills details (last 3 months)
+/// Retrieve the account’s bills. The bill refers to all transaction records that result in changing the balance of an account.
+/// Pagination is supported, and the response is sorted with most recent first. This endpoint can retrieve data from the last 3 months.
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/bills-archive
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct GetBillsArchive(pub GetBills);
+
+impl Request for GetBillsArchive {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/bills-archive";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<Bill>;
+}
+
+impl Paginated for GetBillsArchive {
+    type Item = Bill;
+    type Cursor = String;
+
+    fn cursor(item: &Bill) -> Option<String> {
+        GetBills::cursor(item)
+    }
+
+    fn timestamp(item: &Bill) -> Option<u64> {
+        GetBills::timestamp(item)
+    }
+
+    fn set_after(&mut self, cursor: String) {
+        self.0.set_after(cursor);
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.0.limit
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-interest-accrued-data
+/// ## Get interest accrued data
+/// Get the amount of interest accrued from borrowing in the past year.
+///
+/// Not [`Paginated`]: interest accrues hourly for every borrowed currency, so the records of one hour share their `ts`
+/// and paging with `after` set to the last `ts` skips those of the hour that did not fit in the page.
+/// Filter by `ccy` to page one currency at a time.
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/interest-accrued
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInterestAccrued {
+    /// Loan type, 1: VIP loans, 2: Market loans
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Loan currency, e.g. BTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// Instrument ID, e.g. BTC-USDT, only applicable to MARGIN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// Margin mode, cross or isolated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mgn_mode: Option<MarginMode>,
+    /// Pagination of data to return records earlier than the requested timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Pagination of data to return records newer than the requested timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Number of results per request. The maximum is 100. The default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Request for GetInterestAccrued {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/interest-accrued";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<InterestAccrued>;
+}
+
+/// https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-interest-rate
+/// ## Get interest rate
+/// Get the user's current leveraged currency borrowing market interest rate
+///
+/// Rate Limit: 5 requests per 2 seconds
+/// Rate limit rule: UserID
+/// ### HTTP Requests
+/// **GET** /api/v5/account/interest-rate
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInterestRate {
+    /// Currency, e.g. BTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+}
+
+impl Request for GetInterestRate {
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/account/interest-rate";
+    const AUTH: bool = true;
+    const RATE_LIMIT: Option<RateLimit> = Some(RateLimit::new(
+        5,
+        Duration::from_secs(2),
+        RateLimitRule::UserId,
+    ));
+    type Response = Vec<InterestRate>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tests::{rest, MockTransport};
+    use crate::api::v5::model::ExecType;
+    use futures::TryStreamExt;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_account_config() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"acctLv":"2","autoLoan":false,"ctIsoMode":"automatic","greeksType":"PA","level":"Lv1","levelTmp":"","mgnIsoMode":"automatic","posMode":"long_short_mode","spotOffsetType":"","uid":"44705892343619584","label":"V5 Test","roleType":"0","traderInsts":[],"opAuth":"0","ip":"","perm":"read_only,withdraw,trade","mainUid":"44705892343619584","kycLv":"3"}]}"#,
+        )]);
+        let config = rest(&transport)
+            .request(GetAccountConfig::default())
+            .await
+            .unwrap();
+        assert!(matches!(config[0].pos_mode, Some(PositionMode::LongShort)));
+        assert!(matches!(
+            config[0].ct_iso_mode,
+            Some(IsolatedMarginMode::Automatic)
+        ));
+        assert_eq!(config[0].auto_loan, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_set_position_mode() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"posMode":"net_mode"}]}"#,
+        )]);
+        let config = rest(&transport)
+            .request(SetPositionMode {
+                pos_mode: PositionMode::Net,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(config[0].pos_mode, Some(PositionMode::Net)));
+        assert_eq!(transport.requests()[0].body, r#"{"posMode":"net_mode"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_set_leverage() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"lever":"5","mgnMode":"isolated","instId":"BTC-USDT-SWAP","posSide":"long"}]}"#,
+        )]);
+        let leverage = rest(&transport)
+            .request(SetLeverage {
+                inst_id: Some("BTC-USDT-SWAP".to_owned()),
+                ccy: None,
+                lever: "5".to_owned(),
+                mgn_mode: MarginMode::Isolated,
+                pos_side: Some(PositionSide::Long),
+            })
+            .await
+            .unwrap();
+        assert_eq!(leverage[0].lever, Some(5.0));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"instId":"BTC-USDT-SWAP","lever":"5","mgnMode":"isolated","posSide":"long"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_leverage_info() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ccy":"","instId":"BTC-USDT-SWAP","mgnMode":"cross","posSide":"net","lever":"3"}]}"#,
+        )]);
+        let leverage = rest(&transport)
+            .request(GetLeverageInfo {
+                inst_id: Some("BTC-USDT-SWAP".to_owned()),
+                ccy: None,
+                mgn_mode: MarginMode::Cross,
+            })
+            .await
+            .unwrap();
+        assert_eq!(leverage[0].ccy, None);
+        assert!(matches!(leverage[0].mgn_mode, Some(MarginMode::Cross)));
+        assert!(matches!(leverage[0].pos_side, Some(PositionSide::Net)));
+        assert_eq!(leverage[0].lever, Some(3.0));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT-SWAP&mgnMode=cross")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_max_size() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ccy":"BTC","instId":"BTC-USDT","maxBuy":"0.0500695098559788","maxSell":"64.4798671570072"}]}"#,
+        )]);
+        let sizes = rest(&transport)
+            .request(GetMaxSize {
+                inst_id: "BTC-USDT".to_owned(),
+                td_mode: TradeMode::Isolated,
+                ccy: Some("BTC".to_owned()),
+                px: None,
+                leverage: None,
+                un_spot_offset: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(sizes[0].max_buy, Some(0.0500695098559788));
+        assert_eq!(sizes[0].max_sell, Some(64.4798671570072));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT&tdMode=isolated&ccy=BTC")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_max_avail_size() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","availBuy":"100","availSell":"1"}]}"#,
+        )]);
+        let sizes = rest(&transport)
+            .request(GetMaxAvailSize {
+                inst_id: "BTC-USDT".to_owned(),
+                td_mode: TradeMode::Cash,
+                ccy: None,
+                reduce_only: Some(false),
+                un_spot_offset: None,
+                quick_mgn_type: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(sizes[0].avail_buy, Some(100.0));
+        assert_eq!(sizes[0].avail_sell, Some(1.0));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instId=BTC-USDT&tdMode=cash&reduceOnly=false")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_adjust_margin_balance() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"amt":"0.3","ccy":"BTC","instId":"BTC-USDT","leverage":"","posSide":"net","type":"add"}]}"#,
+        )]);
+        let balances = rest(&transport)
+            .request(AdjustMarginBalance {
+                inst_id: "BTC-USDT".to_owned(),
+                pos_side: PositionSide::Net,
+                r#type: MarginBalanceType::Add,
+                amt: "0.3".to_owned(),
+                ccy: Some("BTC".to_owned()),
+            })
+            .await
+            .unwrap();
+        assert_eq!(balances[0].amt, Some(0.3));
+        assert!(matches!(balances[0].r#type, Some(MarginBalanceType::Add)));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"instId":"BTC-USDT","posSide":"net","type":"add","amt":"0.3","ccy":"BTC"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_isolated_mode() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"isoMode":"automatic"}]}"#,
+        )]);
+        let config = rest(&transport)
+            .request(SetIsolatedMode {
+                iso_mode: IsolatedMarginMode::Automatic,
+                r#type: "MARGIN".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            config[0].iso_mode,
+            Some(IsolatedMarginMode::Automatic)
+        ));
+        assert_eq!(
+            transport.requests()[0].body,
+            r#"{"isoMode":"automatic","type":"MARGIN"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trade_fee() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"category":"1","delivery":"","exercise":"","instType":"SPOT","level":"Lv1","maker":"-0.0008","makerU":"","makerUSDC":"","taker":"-0.001","takerU":"","takerUSDC":"","ts":"1608623351857"}]}"#,
+        )]);
+        let fees = rest(&transport)
+            .request(GetTradeFee {
+                inst_type: InstrumentType::Spot,
+                inst_id: Some("BTC-USDT".to_owned()),
+                uly: None,
+                inst_family: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(fees[0].level.as_deref(), Some("Lv1"));
+        assert_eq!(fees[0].maker, Some(-0.0008));
+        assert_eq!(fees[0].taker, Some(-0.001));
+        assert_eq!(fees[0].maker_u, None);
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=SPOT&instId=BTC-USDT")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_bills() {
+        let transport = MockTransport::new([
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"bal":"8694.2179403378290202","balChg":"0.0219338232210000","billId":"623950854533513219","ccy":"USDT","execType":"T","fee":"-0.000021955779","from":"","instId":"BTC-USDT-SWAP","instType":"SWAP","interest":"0","mgnMode":"isolated","notes":"","ordId":"623950854525124608","pnl":"0","posBal":"0","posBalChg":"0","subType":"2","sz":"1","to":"","ts":"1695033476167","type":"2"},{"bal":"8694.2","balChg":"-0.3","billId":"623950854533513218","ccy":"USDT","instId":"BTC-USDT-SWAP","instType":"SWAP","subType":"999","ts":"1695033476100","type":"8"}]}"#,
+            ),
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"billId":"623950854533513217","ccy":"USDT","instType":"SPOT","subType":"11","ts":"1695033400000","type":"1"}]}"#,
+            ),
+        ]);
+        let req = GetBills {
+            begin: Some(1695000000000),
+            end: Some(1695100000000),
+            limit: Some(2),
+            ..Default::default()
+        };
+        let bills: Vec<_> = rest(&transport)
+            .paginate(req, None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(bills.len(), 3);
+        assert!(matches!(bills[0].r#type, Some(BillType::Trade)));
+        assert!(matches!(bills[0].sub_type, Some(BillSubType::Sell)));
+        assert!(matches!(bills[0].exec_type, Some(ExecType::Taker)));
+        assert!(matches!(bills[1].r#type, Some(BillType::FundingFee)));
+        assert!(matches!(bills[1].sub_type, Some(BillSubType::Other(_))));
+        assert!(matches!(bills[2].sub_type, Some(BillSubType::TransferIn)));
+        assert_eq!(
+            transport.requests()[1].url.query(),
+            Some("after=623950854533513218&begin=1695000000000&end=1695100000000&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_bills_archive() {
+        let transport = MockTransport::new([
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"bal":"8694.2","balChg":"-0.3","billId":"623950854533513219","ccy":"USDT","instType":"SWAP","subType":"173","ts":"1695033476167","type":"8"},{"bal":"8694.5","balChg":"-0.2","billId":"623950854533513218","ccy":"USDT","instType":"SWAP","subType":"173","ts":"1695033476100","type":"8"}]}"#,
+            ),
+            (
+                StatusCode::OK,
+                r#"{"code":"0","msg":"","data":[{"bal":"8694.7","balChg":"-0.1","billId":"623950854533513217","ccy":"USDT","instType":"SWAP","subType":"173","ts":"1695033400000","type":"8"}]}"#,
+            ),
+        ]);
+        let req = GetBillsArchive(GetBills {
+            inst_type: Some(InstrumentType::Swap),
+            limit: Some(2),
+            ..Default::default()
+        });
+        let bills: Vec<_> = rest(&transport)
+            .paginate(req, None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(bills.len(), 3);
+        assert_eq!(bills[2].bill_id.as_deref(), Some("623950854533513217"));
+        let requests = transport.requests();
+        assert_eq!(requests[0].url.path(), "/api/v5/account/bills-archive");
+        assert_eq!(requests[0].url.query(), Some("instType=SWAP&limit=2"));
+        assert_eq!(
+            requests[1].url.query(),
+            Some("instType=SWAP&after=623950854533513218&limit=2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_interest_accrued() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ccy":"USDT","instId":"","interest":"0.0003960833333334","interestRate":"0.0000040833333333","liab":"97","mgnMode":"","ts":"1637312400000","type":"1"}]}"#,
+        )]);
+        let interest = rest(&transport)
+            .request(GetInterestAccrued {
+                ccy: Some("USDT".to_owned()),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(interest[0].inst_id, None);
+        assert!(interest[0].mgn_mode.is_none());
+        assert_eq!(interest[0].liab, Some(97.0));
+        assert_eq!(interest[0].ts, Some(1637312400000));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("ccy=USDT&limit=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_interest_rate() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"ccy":"BTC","interestRate":"0.0001"},{"ccy":"LTC","interestRate":"0.0003"}]}"#,
+        )]);
+        let rates = rest(&transport)
+            .request(GetInterestRate::default())
+            .await
+            .unwrap();
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[1].ccy.as_deref(), Some("LTC"));
+        assert_eq!(rates[1].interest_rate, Some(0.0003));
+        assert_eq!(transport.requests()[0].url.query(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_positions() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"adl":"1","availPos":"0.00190433573","avgPx":"62961.4","baseBal":"","baseBorrowed":"","baseInterest":"","bePx":"","bizRefId":"","bizRefType":"","cTime":"1724740225685","ccy":"BTC","clSpotInUseAmt":"","closeOrderAlgo":[],"deltaBS":"","deltaPA":"","fee":"","fundingFee":"","gammaBS":"","gammaPA":"","idxPx":"62890.5","imr":"","instId":"BTC-USDT","instType":"MARGIN","interest":"0","last":"62892.9","lever":"5","liab":"-99.9998177776581948","liabCcy":"USDT","liqPenalty":"","liqPx":"53615.448336593756","margin":"0.000317654","markPx":"62891.9","maxSpotInUseAmt":"","mgnMode":"isolated","mgnRatio":"9.404143929947395","mmr":"0.0000318005395854","notionalUsd":"119.756628017499","optVal":"","pendingCloseOrdLiabVal":"0","pnl":"","pos":"0.00190433573","posCcy":"BTC","posId":"1752810569801498626","posSide":"net","quoteBal":"","quoteBorrowed":"","quoteInterest":"","realizedPnl":"","spotInUseAmt":"","spotInUseCcy":"","thetaBS":"","thetaPA":"","tradeId":"785524470","uTime":"1724742632153","upl":"-0.0000033452492717","uplLastPx":"-0.0000033199677697","uplRatio":"-0.0105311101755551","uplRatioLastPx":"-0.0104515220008934","usdPx":"","vegaBS":"","vegaPA":""}]}"#,
+        )]);
+        let positions = rest(&transport)
+            .request(GetPositions {
+                inst_type: Some(InstrumentType::Margin),
+                inst_id: Some("BTC-USDT".to_owned()),
+                pos_id: None,
+            })
+            .await
+            .unwrap();
+        let position = &positions[0];
+        assert_eq!(position.inst_type, Some(InstrumentType::Margin));
+        assert_eq!(position.inst_id.as_deref(), Some("BTC-USDT"));
+        assert!(matches!(position.mgn_mode, Some(MarginMode::Isolated)));
+        assert!(matches!(position.pos_side, Some(PositionSide::Net)));
+        assert_eq!(position.avail_pos, Some(0.00190433573));
+        assert_eq!(position.avg_px, Some(62961.4));
+        assert_eq!(position.be_px, None);
+        assert_eq!(position.lever, Some(5.0));
+        assert_eq!(position.liq_px.map(f64::round), Some(53615.0));
+        assert!(position.mgn_ratio.is_some_and(|ratio| ratio > 9.4));
+        assert_eq!(position.liab_ccy.as_deref(), Some("USDT"));
+        assert_eq!(position.adl, Some(1));
+        assert!(position.close_order_algo.is_empty());
+        assert_eq!(position.u_time, Some(1724742632153));
+        assert_eq!(
+            transport.requests()[0].url.query(),
+            Some("instType=MARGIN&instId=BTC-USDT")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_history() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"cTime":"1654177169995","ccy":"BTC","closeAvgPx":"29786.5999999789081085","closeTotalPos":"1","instId":"BTC-USD-SWAP","instType":"SWAP","lever":"10.0","mgnMode":"cross","openAvgPx":"29783.8999999995535393","openMaxPos":"1","realizedPnl":"0.001","fee":"-0.0001","fundingFee":"0","liqPenalty":"0","pnl":"0.0011","pnlRatio":"0.000906447858888","posId":"452587086133239818","posSide":"long","direction":"long","triggerPx":"","type":"1","uTime":"1654177174419","uly":"BTC-USD"}]}"#,
+        )]);
+        let history = rest(&transport)
+            .request(GetPositionsHistory {
+                inst_type: Some(InstrumentType::Swap),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(history[0].pos_id.as_deref(), Some("452587086133239818"));
+        assert_eq!(history[0].r#type.as_deref(), Some("1"));
+        assert_eq!(history[0].u_time, Some(1654177174419));
+        assert_eq!(transport.requests()[0].url.query(), Some("instType=SWAP"));
+    }
+
+    #[tokio::test]
+    async fn test_get_account_position_risk() {
+        let transport = MockTransport::new([(
+            StatusCode::OK,
+            r#"{"code":"0","msg":"","data":[{"adjEq":"174238.6793649711331679","balData":[{"ccy":"BTC","disEq":"78846.7803721021362242","eq":"1.3863533369419636"}],"posData":[{"baseBal":"","ccy":"BTC","instId":"BTC-USDT","instType":"MARGIN","mgnMode":"isolated","notionalCcy":"0","notionalUsd":"0","pos":"0","posCcy":"","posId":"310388685292318723","posSide":"net","quoteBal":""}],"ts":"1620282889345"}]}"#,
+        )]);
+        let risk = rest(&transport)
+            .request(GetAccountPositionRisk::default())
+            .await
+            .unwrap();
+        assert_eq!(risk[0].bal_data[0].ccy.as_deref(), Some("BTC"));
+        assert_eq!(risk[0].pos_data[0].inst_id.as_deref(), Some("BTC-USDT"));
+        assert!(risk[0].pos_data[0].base_bal.is_none());
+        assert_eq!(transport.requests()[0].url.query(), None);
+    }
+}
@@ -0,0 +1,468 @@
+use crate::api::v5::model::{
+    BalanceAndPosition, BookUpdate, Candle, FundingRate, IndexTicker, InstrumentType, MarkPrice,
+    Position, Ticker, Trade, TradingBalanceDetail,
+};
+use crate::api::v5::order_book::algo::AlgoOrderDetail;
+use crate::api::v5::order_book::trade::OrderDetail;
+use crate::api::v5::Request;
+use crate::serde_util::str_opt;
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::fmt::Debug;
+
+/// Arguments of a channel, sent with `subscribe`/`unsubscribe` ops and echoed back in every push
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelArg {
+    /// Channel name
+    pub channel: String,
+    /// Instrument type
+    pub inst_type: Option<InstrumentType>,
+    /// Instrument family
+    pub inst_family: Option<String>,
+    /// Instrument ID
+    pub inst_id: Option<String>,
+    /// Currency
+    pub ccy: Option<String>,
+    /// User Identifier, only pushed by private channels
+    pub uid: Option<String>,
+}
+
+impl ChannelArg {
+    fn with_inst_id(channel: impl Into<String>, inst_id: &str) -> Self {
+        Self {
+            channel: channel.into(),
+            inst_id: Some(inst_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn with_instruments(
+        channel: &str,
+        inst_type: InstrumentType,
+        inst_family: &Option<String>,
+        inst_id: &Option<String>,
+    ) -> Self {
+        Self {
+            channel: channel.to_owned(),
+            inst_type: Some(inst_type),
+            inst_family: inst_family.clone(),
+            inst_id: inst_id.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// WebSocket channels to subscribe through [`WsClient`](crate::api::WsClient)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// `tickers`: last traded price, bid price, ask price and 24-hour trading volume, pushed every 100 ms
+    Tickers { inst_id: String },
+    /// `trades`: the recent trades data, one push can contain multiple aggregated trades
+    Trades { inst_id: String },
+    /// `candle{bar}`: candlesticks, `bar` e.g. `1m`, `1H`, `1Dutc`.
+    /// Served by the business endpoint, see [`WsClient::business`](crate::api::WsClient::business)
+    Candle { bar: String, inst_id: String },
+    /// `books`: 400 depth levels, a snapshot followed by incremental updates every 100 ms
+    Books { inst_id: String },
+    /// `books5`: 5 depth levels, a full snapshot every 100 ms
+    Books5 { inst_id: String },
+    /// `bbo-tbt`: 1 depth level, a full snapshot tick-by-tick
+    BboTbt { inst_id: String },
+    /// `books50-l2-tbt`: 50 depth levels, incremental updates tick-by-tick, requires login (VIP4)
+    Books50L2Tbt { inst_id: String },
+    /// `books-l2-tbt`: 400 depth levels, incremental updates tick-by-tick, requires login (VIP5)
+    BooksL2Tbt { inst_id: String },
+    /// `mark-price`: mark price, pushed every 200 ms when it changes and every 10 seconds otherwise
+    MarkPrice { inst_id: String },
+    /// `index-tickers`: index price, e.g. `BTC-USD`, pushed every 100 ms when it changes
+    IndexTickers { inst_id: String },
+    /// `funding-rate`: funding rate of a perpetual swap, pushed every 30 to 90 seconds
+    FundingRate { inst_id: String },
+    /// `account`: balances of the trading account, requires login
+    Account { ccy: Option<String> },
+    /// `positions`: positions, pushed on order, fill and funding events and every 5 seconds, requires login
+    Positions {
+        inst_type: InstrumentType,
+        inst_family: Option<String>,
+        inst_id: Option<String>,
+    },
+    /// `balance_and_position`: balance and position changes of one event, requires login
+    BalanceAndPosition,
+    /// `orders`: order updates, pushed on placement, fills, amendments and cancellations, requires login
+    Orders {
+        inst_type: InstrumentType,
+        inst_family: Option<String>,
+        inst_id: Option<String>,
+    },
+    /// `orders-algo`: algo order updates, requires login
+    OrdersAlgo {
+        inst_type: InstrumentType,
+        inst_family: Option<String>,
+        inst_id: Option<String>,
+    },
+}
+
+impl Channel {
+    pub fn arg(&self) -> ChannelArg {
+        match self {
+            Channel::Tickers { inst_id } => ChannelArg::with_inst_id("tickers", inst_id),
+            Channel::Trades { inst_id } => ChannelArg::with_inst_id("trades", inst_id),
+            Channel::Candle { bar, inst_id } => {
+                ChannelArg::with_inst_id(format!("candle{bar}"), inst_id)
+            }
+            Channel::Books { inst_id } => ChannelArg::with_inst_id("books", inst_id),
+            Channel::Books5 { inst_id } => ChannelArg::with_inst_id("books5", inst_id),
+            Channel::BboTbt { inst_id } => ChannelArg::with_inst_id("bbo-tbt", inst_id),
+            Channel::Books50L2Tbt { inst_id } => {
+                ChannelArg::with_inst_id("books50-l2-tbt", inst_id)
+            }
+            Channel::BooksL2Tbt { inst_id } => ChannelArg::with_inst_id("books-l2-tbt", inst_id),
+            Channel::MarkPrice { inst_id } => ChannelArg::with_inst_id("mark-price", inst_id),
+            Channel::IndexTickers { inst_id } => ChannelArg::with_inst_id("index-tickers", inst_id),
+            Channel::FundingRate { inst_id } => ChannelArg::with_inst_id("funding-rate", inst_id),
+            Channel::Account { ccy } => ChannelArg {
+                channel: "account".to_owned(),
+                ccy: ccy.clone(),
+                ..Default::default()
+            },
+            Channel::Positions {
+                inst_type,
+                inst_family,
+                inst_id,
+            } => ChannelArg::with_instruments("positions", *inst_type, inst_family, inst_id),
+            Channel::BalanceAndPosition => ChannelArg {
+                channel: "balance_and_position".to_owned(),
+                ..Default::default()
+            },
+            Channel::Orders {
+                inst_type,
+                inst_family,
+                inst_id,
+            } => ChannelArg::with_instruments("orders", *inst_type, inst_family, inst_id),
+            Channel::OrdersAlgo {
+                inst_type,
+                inst_family,
+                inst_id,
+            } => ChannelArg::with_instruments("orders-algo", *inst_type, inst_family, inst_id),
+        }
+    }
+}
+
+impl From<Channel> for ChannelArg {
+    fn from(channel: Channel) -> Self {
+        channel.arg()
+    }
+}
+
+/// Data pushed on a channel, typed by the channel name
+#[derive(Debug, Clone)]
+pub enum ChannelData {
+    Tickers(Vec<Ticker>),
+    Trades(Vec<Trade>),
+    Candle(Vec<Candle>),
+    /// Pushed by every order book channel, apply to an [`OrderBook`](crate::api::v5::order_book::book::OrderBook)
+    Books(Vec<BookUpdate>),
+    MarkPrice(Vec<MarkPrice>),
+    IndexTickers(Vec<IndexTicker>),
+    FundingRate(Vec<FundingRate>),
+    Account(Vec<TradingBalanceDetail>),
+    Positions(Vec<Position>),
+    BalanceAndPosition(Vec<BalanceAndPosition>),
+    Orders(Vec<OrderDetail>),
+    OrdersAlgo(Vec<AlgoOrderDetail>),
+    /// Raw message of a channel without a typed model
+    Other(String),
+}
+
+impl ChannelData {
+    fn parse(channel: &str, text: &str) -> serde_json::Result<Self> {
+        Ok(match channel {
+            "tickers" => ChannelData::Tickers(data(text)?),
+            "trades" => ChannelData::Trades(data(text)?),
+            "books" | "books5" | "bbo-tbt" | "books50-l2-tbt" | "books-l2-tbt" => {
+                ChannelData::Books(data(text)?)
+            }
+            "mark-price" => ChannelData::MarkPrice(data(text)?),
+            "index-tickers" => ChannelData::IndexTickers(data(text)?),
+            "funding-rate" => ChannelData::FundingRate(data(text)?),
+            "account" => ChannelData::Account(data(text)?),
+            "positions" => ChannelData::Positions(data(text)?),
+            "balance_and_position" => ChannelData::BalanceAndPosition(data(text)?),
+            "orders" => ChannelData::Orders(data(text)?),
+            "orders-algo" => ChannelData::OrdersAlgo(data(text)?),
+            candle if candle.starts_with("candle") => ChannelData::Candle(data(text)?),
+            _ => ChannelData::Other(text.to_owned()),
+        })
+    }
+}
+
+fn data<T: DeserializeOwned + Debug>(text: &str) -> serde_json::Result<Vec<T>> {
+    let frame: WsFrame<IgnoredAny, Vec<T>> = serde_json::from_str(text)?;
+    Ok(frame.data.unwrap_or_default())
+}
+
+/// Envelope of a received frame. Unlike [`WsResponse`](crate::api::v5::WsResponse) the strings are owned,
+/// since `msg` may contain escapes, e.g. the op quoted back by error 60012
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WsFrame<A, T> {
+    pub id: Option<String>,
+    pub arg: Option<A>,
+    #[serde(default, with = "str_opt")]
+    pub code: Option<u64>,
+    pub conn_id: Option<String>,
+    pub event: Option<String>,
+    pub action: Option<String>,
+    pub data: Option<T>,
+    pub msg: Option<String>,
+}
+
+/// Message received from a [`WsClient`](crate::api::WsClient)
+#[derive(Debug, Clone)]
+pub enum WsMessage {
+    /// Subscription to the channel succeeded
+    Subscribed(ChannelArg),
+    /// Unsubscription from the channel succeeded
+    Unsubscribed(ChannelArg),
+    /// Data pushed on a subscribed channel.
+    /// `action` is `snapshot` or `update` for incremental channels, `None` otherwise
+    Push {
+        arg: ChannelArg,
+        action: Option<String>,
+        data: ChannelData,
+    },
+    /// Notification from the exchange, e.g. upcoming service upgrade
+    Notice {
+        code: Option<u64>,
+        msg: Option<String>,
+    },
+    /// Failed op, e.g. subscribing to an unknown instrument
+    Error {
+        code: Option<u64>,
+        msg: Option<String>,
+    },
+    /// The connection was lost and reopened, subscriptions were replayed.
+    /// Incremental channels restart with a snapshot, local state should be discarded
+    Reconnected,
+}
+
+impl WsMessage {
+    /// Parses a text frame, returning `None` for events without a message of their own
+    pub(crate) fn from_text(text: &str) -> serde_json::Result<Option<Self>> {
+        let frame: WsFrame<ChannelArg, IgnoredAny> = serde_json::from_str(text)?;
+        let message = match (frame.event.as_deref(), frame.arg) {
+            (Some("subscribe"), Some(arg)) => WsMessage::Subscribed(arg),
+            (Some("unsubscribe"), Some(arg)) => WsMessage::Unsubscribed(arg),
+            (Some("notice"), _) => WsMessage::Notice {
+                code: frame.code,
+                msg: frame.msg,
+            },
+            (Some("error"), _) => WsMessage::Error {
+                code: frame.code,
+                msg: frame.msg,
+            },
+            (None, Some(arg)) if frame.data.is_some() => {
+                let data = ChannelData::parse(&arg.channel, text)?;
+                WsMessage::Push {
+                    arg,
+                    action: frame.action,
+                    data,
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Request which can also be sent as an op on a logged in private WebSocket connection,
+/// see [`WsClient::request`](crate::api::WsClient::request)
+pub trait WsRequest: Request {
+    /// Name of the op, e.g. `order`
+    const OP: &'static str;
+
+    type Arg: Serialize;
+
+    /// Items of the `args` array of the op
+    fn args(&self) -> &[Self::Arg];
+}
+
+/// Request frame of a WebSocket op, e.g. `subscribe`
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub(crate) struct WsOp<'a, A> {
+    pub id: Option<&'a str>,
+    pub op: &'a str,
+    pub args: &'a [A],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::v5::model::CandleState;
+
+    #[test]
+    fn test_serialize_subscribe_op() {
+        let args = [Channel::Candle {
+            bar: "1m".to_owned(),
+            inst_id: "BTC-USDT".to_owned(),
+        }
+        .arg()];
+        let op = WsOp {
+            id: None,
+            op: "subscribe",
+            args: &args,
+        };
+        assert_eq!(
+            serde_json::to_string(&op).unwrap(),
+            r#"{"op":"subscribe","args":[{"channel":"candle1m","instId":"BTC-USDT"}]}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_request_op() {
+        use crate::api::v5::order_book::trade::{CancelMultipleOrders, CancelOrder};
+
+        let orders: CancelMultipleOrders = vec![CancelOrder {
+            inst_id: "BTC-USDT".to_owned(),
+            ord_id: Some("2510789768709120".to_owned()),
+            cl_ord_id: None,
+        }];
+        let op = WsOp {
+            id: Some("1514"),
+            op: CancelMultipleOrders::OP,
+            args: orders.args(),
+        };
+        assert_eq!(
+            serde_json::to_string(&op).unwrap(),
+            r#"{"id":"1514","op":"batch-cancel-orders","args":[{"instId":"BTC-USDT","ordId":"2510789768709120"}]}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let text = r#"{"event":"subscribe","arg":{"channel":"tickers","instId":"BTC-USDT"},"connId":"accb8e21"}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Subscribed(arg)) => assert_eq!(
+                arg,
+                Channel::Tickers {
+                    inst_id: "BTC-USDT".to_owned()
+                }
+                .arg()
+            ),
+            other => panic!("unexpected message {other:?}"),
+        }
+
+        let text =
+            r#"{"event":"error","code":"60012","msg":"Invalid request","connId":"a4d3ae55"}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Error { code, .. }) => assert_eq!(code, Some(60012)),
+            other => panic!("unexpected message {other:?}"),
+        }
+
+        // the op is quoted back with escapes, which cannot be borrowed from the frame
+        let text = r#"{"event":"error","code":"60012","msg":"Invalid request: {\"op\": \"subscribe\", \"args\":[{ \"channel\" : \"tickers\"}]}","connId":"a4d3ae55"}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Error { code, msg }) => {
+                assert_eq!(code, Some(60012));
+                assert_eq!(
+                    msg.as_deref(),
+                    Some(
+                        r#"Invalid request: {"op": "subscribe", "args":[{ "channel" : "tickers"}]}"#
+                    )
+                );
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+
+        let text = r#"{"event":"channel-conn-count","channel":"orders","connCount":"2","connId":"abcd1234"}"#;
+        assert!(WsMessage::from_text(text).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_ticker_push() {
+        let text = r#"{"arg":{"channel":"tickers","instId":"BTC-USDT"},"data":[{"instType":"SPOT","instId":"BTC-USDT","last":"9999.99","lastSz":"0.1","askPx":"9999.99","askSz":"11","bidPx":"8888.88","bidSz":"5","open24h":"9000","high24h":"10000","low24h":"8888.88","volCcy24h":"2222","vol24h":"2222","sodUtc0":"2222","sodUtc8":"2222","ts":"1597026383085"}]}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Push {
+                arg,
+                action,
+                data: ChannelData::Tickers(tickers),
+            }) => {
+                assert_eq!(arg.inst_id.as_deref(), Some("BTC-USDT"));
+                assert!(action.is_none());
+                assert_eq!(tickers[0].last, Some("9999.99".parse().unwrap()));
+                assert_eq!(tickers[0].ts, Some(1597026383085));
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_candle_push() {
+        let text = r#"{"arg":{"channel":"candle1D","instId":"BTC-USDT"},"data":[["1597026383085","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","5295858061","0"]]}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Push {
+                data: ChannelData::Candle(candles),
+                ..
+            }) => {
+                assert_eq!(candles[0].ts, Some(1597026383085));
+                assert_eq!(candles[0].close, Some("8548.26".parse().unwrap()));
+                assert_eq!(candles[0].confirm, Some(CandleState::Uncompleted));
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_books_push() {
+        let text = r#"{"arg":{"channel":"books","instId":"BTC-USDT"},"action":"snapshot","data":[{"asks":[["8476.98","415","0","13"],["8477","7","0","2"]],"bids":[["8476.97","256","0","12"],["8475.55","101","0","1"]],"ts":"1597026383085","checksum":-855196043,"prevSeqId":-1,"seqId":123456}]}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Push {
+                action,
+                data: ChannelData::Books(books),
+                ..
+            }) => {
+                assert_eq!(action.as_deref(), Some("snapshot"));
+                assert_eq!(books[0].asks[0].px, "8476.98");
+                assert_eq!(books[0].bids[1].ord_count, Some(1));
+                assert_eq!(books[0].checksum, Some(-855196043));
+                assert_eq!(books[0].seq_id, Some(123456));
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_private_pushes() {
+        let text = r#"{"arg":{"channel":"orders","instType":"SPOT","instId":"BTC-USDT","uid":"614488474791936"},"data":[{"accFillSz":"0.001","avgPx":"31527.1","cTime":"1654084334977","category":"normal","ccy":"","clOrdId":"","fee":"-0.02522168","feeCcy":"USDT","fillPx":"31527.1","fillSz":"0.001","fillTime":"1654084353263","instId":"BTC-USDT","instType":"SPOT","lever":"0","ordId":"452197707845865472","ordType":"limit","pnl":"0","posSide":"","px":"31527.1","side":"sell","state":"filled","sz":"0.001","tag":"","tdMode":"cash","tradeId":"242589207","uTime":"1654084353264"}]}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Push {
+                arg,
+                data: ChannelData::Orders(orders),
+                ..
+            }) => {
+                assert_eq!(arg.inst_type, Some(InstrumentType::Spot));
+                assert_eq!(orders[0].ord_id.as_deref(), Some("452197707845865472"));
+                assert_eq!(orders[0].fill_time, Some(1654084353263));
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+
+        let text = r#"{"arg":{"channel":"positions","instType":"ANY","uid":"77982378738415879"},"data":[{"adl":"1","availPos":"1","avgPx":"2566.31","cTime":"1619507758793","ccy":"ETH","instId":"ETH-USD-210430","instType":"FUTURES","lever":"10","liqPx":"2352.86","margin":"0.0003896645377994","mgnMode":"isolated","pos":"1","posCcy":"","posId":"307173036051017730","posSide":"long","upl":"-0.0000009932766034","closeOrderAlgo":[{"algoId":"123","slTriggerPx":"123","slTriggerPxType":"mark","closeFraction":"0.6"}],"uTime":"1619507761462","pTime":"1619507761462"}]}"#;
+        match WsMessage::from_text(text).unwrap() {
+            Some(WsMessage::Push {
+                data: ChannelData::Positions(positions),
+                ..
+            }) => {
+                assert_eq!(positions[0].inst_id.as_deref(), Some("ETH-USD-210430"));
+                assert_eq!(positions[0].adl, Some(1));
+                assert_eq!(positions[0].close_order_algo.len(), 1);
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,659 @@
+use crate::api::credential::Credential;
+use crate::api::error::{ApiError, Error};
+use crate::api::v5::ws::{Channel, ChannelArg, WsFrame, WsMessage, WsOp, WsRequest};
+use crate::api::{Options, RetryPolicy};
+
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Heartbeat and reconnection settings of [`WsClient`]
+#[derive(Debug, Clone)]
+pub struct WsConfig {
+    /// Send a `ping` when nothing was received for this long.
+    /// OKX closes connections idle for 30 seconds.
+    pub ping_interval: Duration,
+    /// Consider the connection dead when the `pong` takes longer than this
+    pub pong_timeout: Duration,
+    /// Backoff between reconnection attempts, `None` ends the client on disconnection
+    pub reconnect: Option<RetryPolicy>,
+    /// How long [`WsClient::request`] waits for the response of an op
+    pub request_timeout: Duration,
+}
+
+/// Shortest period of the heartbeat timer, whatever the configured durations
+const MIN_HEARTBEAT_PERIOD: Duration = Duration::from_millis(10);
+
+impl WsConfig {
+    /// Period of the timer checking the ping interval and pong timeout
+    fn heartbeat_period(&self) -> Duration {
+        (self.ping_interval.min(self.pong_timeout) / 2).max(MIN_HEARTBEAT_PERIOD)
+    }
+}
+
+impl Default for WsConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(20),
+            pong_timeout: Duration::from_secs(10),
+            reconnect: Some(RetryPolicy {
+                max_retries: u32::MAX,
+                initial_backoff: Duration::from_millis(500),
+                max_backoff: Duration::from_secs(30),
+                ..Default::default()
+            }),
+            request_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+enum Command {
+    Subscribe(Vec<ChannelArg>),
+    Unsubscribe(Vec<ChannelArg>),
+    Request {
+        id: String,
+        text: String,
+        response: oneshot::Sender<String>,
+    },
+}
+
+/// WebSocket client, the connection is driven by a background task
+/// which forwards every parsed message to [`WsClient::next`].
+///
+/// The task pings the exchange when the connection is idle, and on disconnection reconnects,
+/// logs in again and resubscribes to every active channel before yielding [`WsMessage::Reconnected`].
+pub struct WsClient {
+    commands: mpsc::UnboundedSender<Command>,
+    messages: mpsc::UnboundedReceiver<WsMessage>,
+    next_id: AtomicU64,
+    request_timeout: Duration,
+}
+
+impl WsClient {
+    /// Connects to the public endpoint, serving market data channels
+    pub async fn public(options: &Options) -> Result<Self, Error> {
+        Self::connect(options.public_websocket(), options, false).await
+    }
+
+    /// Connects to the business endpoint, serving the candlestick channels
+    pub async fn business(options: &Options) -> Result<Self, Error> {
+        Self::connect(options.business_websocket(), options, false).await
+    }
+
+    /// Connects to the private endpoint and logs in with the API key of `options`,
+    /// serving the account, position and order channels
+    pub async fn private(options: &Options) -> Result<Self, Error> {
+        Self::connect(options.private_websocket(), options, true).await
+    }
+
+    async fn connect(url: &str, options: &Options, private: bool) -> Result<Self, Error> {
+        let login_with = match private {
+            true => Some(Login::new(options)?),
+            false => None,
+        };
+        let (mut socket, _) = connect_async(url).await?;
+        if let Some(credential) = &login_with {
+            login(&mut socket, credential).await?;
+        }
+
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let (messages_tx, messages) = mpsc::unbounded_channel();
+        tokio::spawn(
+            Connection {
+                url: url.to_owned(),
+                login: login_with,
+                config: options.websocket.clone(),
+                socket,
+                commands: commands_rx,
+                messages: messages_tx,
+                pending: HashMap::new(),
+                subscriptions: Vec::new(),
+            }
+            .run(),
+        );
+
+        Ok(Self {
+            commands,
+            messages,
+            next_id: AtomicU64::new(1),
+            request_timeout: options.websocket.request_timeout,
+        })
+    }
+
+    pub fn subscribe(
+        &self,
+        channels: impl IntoIterator<Item = Channel>,
+    ) -> crate::api::error::Result<()> {
+        let args = channels.into_iter().map(ChannelArg::from).collect();
+        self.commands
+            .send(Command::Subscribe(args))
+            .map_err(|_| Error::Disconnected)
+    }
+
+    pub fn unsubscribe(
+        &self,
+        channels: impl IntoIterator<Item = Channel>,
+    ) -> crate::api::error::Result<()> {
+        let args = channels.into_iter().map(ChannelArg::from).collect();
+        self.commands
+            .send(Command::Unsubscribe(args))
+            .map_err(|_| Error::Disconnected)
+    }
+
+    /// Sends an op on a logged in private connection, e.g. placing an order,
+    /// and waits for the response with the same `id`.
+    /// The response is the same as when sending the request over REST.
+    ///
+    /// Fails with [`Error::Disconnected`] when the connection drops before the response arrives,
+    /// or with [`Error::Timeout`] when none arrives within [`WsConfig::request_timeout`],
+    /// e.g. when OKX rejects the op with an `error` event which carries no `id`.
+    /// In both cases the op may or may not have been executed.
+    pub async fn request<R>(&self, req: R) -> crate::api::error::Result<R::Response>
+    where
+        R: WsRequest,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        let text = serde_json::to_string(&WsOp {
+            id: Some(&id),
+            op: R::OP,
+            args: req.args(),
+        })?;
+
+        let (response, receiver) = oneshot::channel();
+        self.commands
+            .send(Command::Request { id, text, response })
+            .map_err(|_| Error::Disconnected)?;
+        let text = tokio::time::timeout(self.request_timeout, receiver)
+            .await
+            .map_err(|_| Error::Timeout(self.request_timeout))?
+            .map_err(|_| Error::Disconnected)?;
+
+        let frame: WsFrame<IgnoredAny, R::Response> = serde_json::from_str(&text)?;
+        if let (Some(0), Some(data)) = (frame.code, frame.data) {
+            return Ok(data);
+        }
+        // the data of failed ops, e.g. the orders of a failed batch, is kept as raw JSON
+        let frame: WsFrame<IgnoredAny, serde_json::Value> = serde_json::from_str(&text)?;
+        Err(Error::Api(Box::new(ApiError {
+            code: frame.code,
+            msg: frame.msg,
+            data: frame.data,
+            conn_id: frame.conn_id,
+            ..Default::default()
+        })))
+    }
+
+    /// Next message, `None` once the connection is closed for good
+    pub async fn next(&mut self) -> Option<WsMessage> {
+        self.messages.recv().await
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginArg<'a> {
+    api_key: &'a str,
+    passphrase: &'a str,
+    timestamp: &'a str,
+    sign: &'a str,
+}
+
+/// Credentials kept by the connection to log in again after reconnecting
+struct Login {
+    credential: Credential,
+    passphrase: String,
+}
+
+impl Login {
+    fn new(options: &Options) -> Result<Self, Error> {
+        let passphrase = options
+            .passphrase
+            .clone()
+            .ok_or(Error::NoSecretConfigured)?;
+        let credential: Credential = match options.try_into() {
+            Ok(credential) => credential,
+            Err(_) => return Err(Error::NoSecretConfigured),
+        };
+        Ok(Self {
+            credential,
+            passphrase,
+        })
+    }
+}
+
+/// https://www.okx.com/docs-v5/en/#overview-websocket-login
+/// Sends the `login` op and waits for its result
+async fn login(socket: &mut Socket, login: &Login) -> Result<(), Error> {
+    let timestamp = Utc::now().timestamp().to_string();
+    let (api_key, sign) =
+        login
+            .credential
+            .signature_websocket(Method::GET, &timestamp, "/users/self/verify");
+    let text = serde_json::to_string(&WsOp {
+        id: None,
+        op: "login",
+        args: &[LoginArg {
+            api_key,
+            passphrase: &login.passphrase,
+            timestamp: &timestamp,
+            sign: &sign,
+        }],
+    })?;
+    socket.send(Message::Text(text)).await?;
+
+    while let Some(frame) = socket.next().await {
+        let Message::Text(text) = frame? else {
+            continue;
+        };
+        let frame: WsFrame<IgnoredAny, IgnoredAny> = serde_json::from_str(&text)?;
+        match frame.event.as_deref() {
+            Some("login") if frame.code == Some(0) => return Ok(()),
+            Some("login" | "error") => {
+                return Err(Error::Api(Box::new(ApiError {
+                    code: frame.code,
+                    msg: frame.msg,
+                    conn_id: frame.conn_id,
+                    ..Default::default()
+                })))
+            }
+            _ => {}
+        }
+    }
+    Err(Error::Disconnected)
+}
+
+/// Why [`Connection::drive`] returned
+enum Exit {
+    /// The client was dropped
+    Closed,
+    /// The connection was lost
+    Disconnected,
+}
+
+struct Connection {
+    url: String,
+    login: Option<Login>,
+    config: WsConfig,
+    socket: Socket,
+    commands: mpsc::UnboundedReceiver<Command>,
+    messages: mpsc::UnboundedSender<WsMessage>,
+    pending: HashMap<String, oneshot::Sender<String>>,
+    subscriptions: Vec<ChannelArg>,
+}
+
+impl Connection {
+    async fn run(mut self) {
+        while let Exit::Disconnected = self.drive().await {
+            // responses of in-flight requests are lost with the connection
+            self.pending.clear();
+
+            let Some(socket) = self.reconnect().await else {
+                return;
+            };
+            self.socket = socket;
+
+            if !self.subscriptions.is_empty() {
+                let args = self.subscriptions.clone();
+                if let Err(err) = self.send_op("subscribe", &args).await {
+                    log::error!("ws resubscribe failed: {err}");
+                    continue;
+                }
+            }
+            if self.messages.send(WsMessage::Reconnected).is_err() {
+                return;
+            }
+        }
+        let _ = self.socket.close(None).await;
+    }
+
+    async fn drive(&mut self) -> Exit {
+        let mut heartbeat = tokio::time::interval(self.config.heartbeat_period());
+        let mut last_received = Instant::now();
+        let mut ping_sent: Option<Instant> = None;
+
+        loop {
+            tokio::select! {
+                command = self.commands.recv() => {
+                    let Some(command) = command else {
+                        return Exit::Closed;
+                    };
+                    if let Err(err) = self.on_command(command).await {
+                        log::error!("{err}");
+                        return Exit::Disconnected;
+                    }
+                },
+                frame = self.socket.next() => {
+                    last_received = Instant::now();
+                    ping_sent = None;
+                    match frame {
+                        Some(Ok(Message::Text(text))) => self.on_text(&text),
+                        Some(Ok(Message::Close(frame))) => {
+                            log::warn!("ws closed {frame:?}");
+                            return Exit::Disconnected;
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(err)) => {
+                            log::error!("{err}");
+                            return Exit::Disconnected;
+                        }
+                        None => return Exit::Disconnected,
+                    }
+                },
+                _ = heartbeat.tick() => match ping_sent {
+                    Some(sent) if sent.elapsed() >= self.config.pong_timeout => {
+                        log::warn!("ws pong not received within {:?}", self.config.pong_timeout);
+                        return Exit::Disconnected;
+                    }
+                    None if last_received.elapsed() >= self.config.ping_interval => {
+                        if let Err(err) = self.socket.send(Message::Text("ping".to_owned())).await {
+                            log::error!("{err}");
+                            return Exit::Disconnected;
+                        }
+                        ping_sent = Some(Instant::now());
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    /// Opens a new connection with backoff, `None` when giving up or when the client was dropped
+    async fn reconnect(&mut self) -> Option<Socket> {
+        let policy = self.config.reconnect.clone()?;
+        for attempt in 0..policy.max_retries {
+            let delay = policy.backoff(attempt);
+            log::warn!("ws reconnecting to {} in {:?}", self.url, delay);
+            tokio::time::sleep(delay).await;
+            if self.messages.is_closed() {
+                return None;
+            }
+
+            match self.open().await {
+                Ok(socket) => return Some(socket),
+                Err(err) => log::error!("ws reconnect failed: {err}"),
+            }
+        }
+        None
+    }
+
+    async fn open(&self) -> Result<Socket, Error> {
+        let (mut socket, _) = connect_async(&self.url).await?;
+        if let Some(credential) = &self.login {
+            login(&mut socket, credential).await?;
+        }
+        Ok(socket)
+    }
+
+    async fn on_command(
+        &mut self,
+        command: Command,
+    ) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+        match command {
+            Command::Subscribe(args) => {
+                for arg in &args {
+                    if !self.subscriptions.contains(arg) {
+                        self.subscriptions.push(arg.clone());
+                    }
+                }
+                self.send_op("subscribe", &args).await
+            }
+            Command::Unsubscribe(args) => {
+                self.subscriptions.retain(|arg| !args.contains(arg));
+                self.send_op("unsubscribe", &args).await
+            }
+            Command::Request { id, text, response } => {
+                log::debug!("ws send {text}");
+                // forget the requests which timed out
+                self.pending.retain(|_, response| !response.is_closed());
+                self.pending.insert(id, response);
+                self.socket.send(Message::Text(text)).await
+            }
+        }
+    }
+
+    async fn send_op(
+        &mut self,
+        op: &str,
+        args: &[ChannelArg],
+    ) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+        let text = serde_json::to_string(&WsOp { id: None, op, args })
+            .expect("channel args are serializable");
+        log::debug!("ws send {text}");
+        self.socket.send(Message::Text(text)).await
+    }
+
+    fn on_text(&mut self, text: &str) {
+        if text == "pong" {
+            return;
+        }
+
+        if !self.pending.is_empty() {
+            if let Ok(WsFrame { id: Some(id), .. }) =
+                serde_json::from_str::<WsFrame<IgnoredAny, IgnoredAny>>(text)
+            {
+                if let Some(response) = self.pending.remove(&id) {
+                    let _ = response.send(text.to_owned());
+                    return;
+                }
+            }
+        }
+
+        match WsMessage::from_text(text) {
+            Ok(Some(message)) => {
+                let _ = self.messages.send(message);
+            }
+            Ok(None) => {}
+            Err(err) => log::error!("{err}: {text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::v5::order_book::trade::{CancelMultipleOrders, CancelOrder};
+    use crate::api::LiveTrading;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    type ServerSocket = WebSocketStream<TcpStream>;
+
+    const LOGIN_OK: &str = r#"{"event":"login","code":"0","msg":"","connId":"a4d3ae55"}"#;
+
+    fn config(ping_interval: Duration, pong_timeout: Duration) -> WsConfig {
+        WsConfig {
+            ping_interval,
+            pong_timeout,
+            reconnect: Some(RetryPolicy {
+                initial_backoff: Duration::ZERO,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    async fn listen() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        (listener, url)
+    }
+
+    async fn accept(listener: &TcpListener) -> ServerSocket {
+        let (stream, _) = listener.accept().await.unwrap();
+        accept_async(stream).await.unwrap()
+    }
+
+    async fn recv_text(socket: &mut ServerSocket) -> String {
+        loop {
+            match socket.next().await.unwrap().unwrap() {
+                Message::Text(text) => return text,
+                _ => continue,
+            }
+        }
+    }
+
+    async fn accept_login(listener: &TcpListener) -> ServerSocket {
+        let mut socket = accept(listener).await;
+        assert!(recv_text(&mut socket).await.contains(r#""op":"login""#));
+        socket
+            .send(Message::Text(LOGIN_OK.to_owned()))
+            .await
+            .unwrap();
+        socket
+    }
+
+    async fn next(client: &mut WsClient) -> Option<WsMessage> {
+        tokio::time::timeout(Duration::from_secs(5), client.next())
+            .await
+            .expect("no message within 5s")
+    }
+
+    #[test]
+    fn test_heartbeat_period() {
+        assert_eq!(
+            WsConfig::default().heartbeat_period(),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            config(Duration::ZERO, Duration::from_secs(1)).heartbeat_period(),
+            MIN_HEARTBEAT_PERIOD
+        );
+        assert_eq!(
+            config(Duration::from_nanos(1), Duration::from_nanos(1)).heartbeat_period(),
+            MIN_HEARTBEAT_PERIOD
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_login_and_subscriptions() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept_login(&listener).await;
+            let first = recv_text(&mut socket).await;
+            drop(socket);
+
+            let mut socket = accept_login(&listener).await;
+            let replayed = recv_text(&mut socket).await;
+            (first, replayed, socket)
+        });
+
+        let options = Options::new_with_credential(LiveTrading, "key", "secret", "passphrase")
+            .with_websocket(config(Duration::from_secs(20), Duration::from_secs(10)));
+        let mut client = WsClient::connect(&url, &options, true).await.unwrap();
+        client
+            .subscribe([Channel::Tickers {
+                inst_id: "BTC-USDT".to_owned(),
+            }])
+            .unwrap();
+
+        assert!(matches!(
+            next(&mut client).await,
+            Some(WsMessage::Reconnected)
+        ));
+        let (first, replayed, _socket) = server.await.unwrap();
+        assert_eq!(
+            first,
+            r#"{"op":"subscribe","args":[{"channel":"tickers","instId":"BTC-USDT"}]}"#
+        );
+        assert_eq!(replayed, first);
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_when_pong_is_missing() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept(&listener).await;
+            let ping = recv_text(&mut socket).await;
+            // the ping is left unanswered, the client gives up on this connection
+            let reconnected = accept(&listener).await;
+            (ping, socket, reconnected)
+        });
+
+        let options = Options::new(LiveTrading)
+            .with_websocket(config(Duration::from_millis(50), Duration::from_millis(50)));
+        let mut client = WsClient::connect(&url, &options, false).await.unwrap();
+
+        assert!(matches!(
+            next(&mut client).await,
+            Some(WsMessage::Reconnected)
+        ));
+        let (ping, _, _) = server.await.unwrap();
+        assert_eq!(ping, "ping");
+    }
+
+    fn cancel_order() -> CancelMultipleOrders {
+        vec![CancelOrder {
+            inst_id: "BTC-USDT".to_owned(),
+            ord_id: Some("2510789768709120".to_owned()),
+            cl_ord_id: None,
+        }]
+    }
+
+    #[tokio::test]
+    async fn test_request_times_out_without_response() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept_login(&listener).await;
+            let op = recv_text(&mut socket).await;
+            // the op is left unanswered
+            (op, socket)
+        });
+
+        let options = Options::new_with_credential(LiveTrading, "key", "secret", "passphrase")
+            .with_websocket(WsConfig {
+                request_timeout: Duration::from_millis(50),
+                ..config(Duration::from_secs(20), Duration::from_secs(10))
+            });
+        let client = WsClient::connect(&url, &options, true).await.unwrap();
+
+        let err = client.request(cancel_order()).await.unwrap_err();
+        assert!(matches!(err, Error::Timeout(timeout) if timeout == Duration::from_millis(50)));
+        let (op, _socket) = server.await.unwrap();
+        assert!(op.contains(r#""op":"batch-cancel-orders""#));
+    }
+
+    #[tokio::test]
+    async fn test_request_error_with_escaped_msg() {
+        let (listener, url) = listen().await;
+        let server = tokio::spawn(async move {
+            let mut socket = accept_login(&listener).await;
+            let op = recv_text(&mut socket).await;
+            let op: serde_json::Value = serde_json::from_str(&op).unwrap();
+            let id = op["id"].as_str().unwrap();
+            let response = format!(
+                r#"{{"id":"{id}","op":"batch-cancel-orders","code":"60012","msg":"Invalid request: {{\"op\": \"batch-cancel-orders\"}}","data":[]}}"#
+            );
+            socket.send(Message::Text(response)).await.unwrap();
+            socket
+        });
+
+        let options = Options::new_with_credential(LiveTrading, "key", "secret", "passphrase")
+            .with_websocket(config(Duration::from_secs(20), Duration::from_secs(10)));
+        let client = WsClient::connect(&url, &options, true).await.unwrap();
+
+        match client.request(cancel_order()).await {
+            Err(Error::Api(err)) => {
+                assert_eq!(err.code, Some(60012));
+                assert_eq!(
+                    err.msg.as_deref(),
+                    Some(r#"Invalid request: {"op": "batch-cancel-orders"}"#)
+                );
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+        let _socket = server.await.unwrap();
+    }
+}
@@ -1,2 +1,4 @@
 pub mod api;
 pub mod serde_util;
+
+pub use api::error::Error;
@@ -271,9 +271,22 @@ pub(crate) mod serde_float {
     }
 }
 
+/// Price, size or amount: `f64`, or [`rust_decimal::Decimal`] with the `decimal` feature
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Price or size of an order request, sent as is: `String`, or [`rust_decimal::Decimal`] with the `decimal` feature
+#[cfg(not(feature = "decimal"))]
+pub type OrderNumber = String;
+#[cfg(feature = "decimal")]
+pub type OrderNumber = rust_decimal::Decimal;
+
 // #[derive(Debug, Clone, Copy, PartialEq)]
 // pub struct FloatOpt(Option<f64>);
 pub type MaybeFloat = Option<f64>;
+pub type MaybeNumber = Option<Number>;
 pub type MaybeU64 = Option<u64>;
 pub type MaybeI64 = Option<i64>;
 pub type MaybeString = Option<String>;
@@ -366,6 +379,29 @@ mod tests_maybe_float {
     }
 }
 
+#[cfg(test)]
+mod tests_maybe_number {
+    use super::{str_opt, MaybeNumber};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Foo {
+        #[serde(default, with = "str_opt")]
+        bar: MaybeNumber,
+    }
+
+    #[test]
+    fn test_round_trip_tick_size() {
+        let m = serde_json::from_str::<Foo>(r#"{"bar":"0.0001"}"#).unwrap();
+        #[cfg(feature = "decimal")]
+        assert_eq!(m.bar, Some(rust_decimal::Decimal::new(1, 4)));
+        assert_eq!(serde_json::to_string(&m).unwrap(), r#"{"bar":"0.0001"}"#);
+
+        let m = serde_json::from_str::<Foo>(r#"{"bar":""}"#).unwrap();
+        assert_eq!(m.bar, None);
+    }
+}
+
 #[cfg(test)]
 mod tests_maybe_u64 {
     use serde::Deserialize;